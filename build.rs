@@ -0,0 +1,25 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use std::{env, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(serdapt_core_net)");
+    // `core::net` was stabilized in Rust 1.77
+    if matches!(rustc_minor_version(), Some(minor) if minor >= 77) {
+        println!("cargo:rustc-cfg=serdapt_core_net");
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    version
+        .split_whitespace()
+        .nth(1)?
+        .split('.')
+        .nth(1)?
+        .parse()
+        .ok()
+}
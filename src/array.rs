@@ -11,7 +11,11 @@ use serde::{
 /// Adapter to customize how array items are serialized
 ///
 /// This adapter serializes the array as a serde tuple. This implies the length is statically known
-/// without looking at the serialized data when deserializing.
+/// without looking at the serialized data when deserializing. Unlike with [`Seq`](crate::Seq),
+/// which serializes a variable-length sequence, formats like bincode then do not encode the length.
+///
+/// References to arrays can be serialized as well. They cannot be deserialized though, as items
+/// cannot in general be borrowed from the serialized data.
 ///
 /// # Example
 /// ```
@@ -64,6 +68,15 @@ where
     }
 }
 
+impl<const N: usize, F, T> SerializeWith<&[T; N]> for Array<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &&[T; N], serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_with(*value, serializer)
+    }
+}
+
 impl<'de, const N: usize, F, T> DeserializeWith<'de, [T; N]> for Array<F>
 where
     F: DeserializeWith<'de, T>,
@@ -138,14 +151,21 @@ impl<const N: usize, T> MaybeUninitArray<N, T> {
         I: IntoIterator<Item = Result<T, E>>,
         E: serde::de::Error,
     {
-        self.items.iter_mut().zip(it).try_for_each(|(out, x)| {
-            out.write(x?);
-            self.count += 1;
-            Ok(())
-        })?;
+        let mut it = it.into_iter();
+        self.items
+            .iter_mut()
+            .zip(&mut it)
+            .try_for_each(|(out, x)| {
+                out.write(x?);
+                self.count += 1;
+                Ok(())
+            })?;
         if self.count != N {
             return Err(E::invalid_length(self.count, &ExpectedArrayLength::<N>));
         }
+        if it.next().is_some() {
+            return Err(E::invalid_length(N + 1, &ExpectedArrayLength::<N>));
+        }
 
         self.count = 0;
         let items = core::mem::replace(
@@ -169,8 +189,8 @@ impl<const N: usize, T> Drop for MaybeUninitArray<N, T> {
 
 #[cfg(all(feature = "alloc", test))]
 mod tests {
-    use crate::test_utils::check_serialization;
-    use serde::{Deserialize, Serialize};
+    use crate::{test_utils::check_serialization, DeserializeWith};
+    use serde::{de::value::SeqDeserializer, Deserialize, Serialize};
     use serde_json::json;
 
     #[derive(Debug, Deserialize, PartialEq, Serialize)]
@@ -188,4 +208,66 @@ mod tests {
     fn array_roundtrips() {
         check_serialization(Foo { xs: [1, 2, 3] }, json!({ "xs": ["1", "2", "3"] }));
     }
+
+    #[test]
+    fn too_many_items_fail() {
+        serde_json::from_value::<Foo<2>>(json!({ "xs": ["1", "2", "3"] })).unwrap_err();
+        serde_json::from_str::<Foo<2>>(r#"{ "xs": ["1", "2", "3"] }"#).unwrap_err();
+    }
+
+    #[test]
+    fn too_many_items_are_reported_by_array_visitor() {
+        let deserializer =
+            SeqDeserializer::<_, serde::de::value::Error>::new(["1", "2", "3"].into_iter());
+        let e =
+            <crate::Array<crate::Str> as DeserializeWith<[i32; 2]>>::deserialize_with(deserializer)
+                .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid length 3, expected an array of length 2"
+        );
+    }
+
+    #[test]
+    fn too_few_items_fail() {
+        serde_json::from_value::<Foo<2>>(json!({ "xs": ["1"] })).unwrap_err();
+    }
+
+    #[derive(Serialize)]
+    struct RefFoo<'a, const N: usize> {
+        #[serde(with = "crate::Array::<crate::Str>")]
+        xs: &'a [i32; N],
+    }
+
+    #[test]
+    fn by_ref_array_roundtrips() {
+        let original = RefFoo { xs: &[1, 2, 3] };
+        assert_eq!(
+            serde_json::to_value(&original).unwrap(),
+            json!({ "xs": ["1", "2", "3"] })
+        );
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Foo<3>>(&serialized).unwrap();
+        assert_eq!(deserialized.xs, *original.xs);
+    }
+
+    #[derive(Serialize)]
+    struct SeqFoo {
+        #[serde(with = "crate::Seq::<crate::Id>")]
+        xs: [u8; 2],
+    }
+
+    #[derive(Serialize)]
+    struct ArrayFoo {
+        #[serde(with = "crate::Array::<crate::Id>")]
+        xs: [u8; 2],
+    }
+
+    #[test]
+    fn array_length_is_not_encoded() {
+        let serialized = bincode::serialize(&ArrayFoo { xs: [1, 2] }).unwrap();
+        assert_eq!(serialized, [1, 2]);
+        let serialized = bincode::serialize(&SeqFoo { xs: [1, 2] }).unwrap();
+        assert_eq!(serialized, [2, 0, 0, 0, 0, 0, 0, 0, 1, 2]);
+    }
 }
@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{marker::PhantomData, sync::atomic::Ordering};
+use serde::{Deserializer, Serializer};
+
+/// Adapter for atomic types such as [`AtomicU32`](core::sync::atomic::AtomicU32)
+///
+/// The value is loaded with the ordering selected by `O` and serialized with `F`. See
+/// [`LoadOrdering`] for the available orderings.
+///
+/// # Example
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::Atomic::<serdapt::Str>")] AtomicU32);
+///
+/// let serialized = serde_json::to_value(Foo(AtomicU32::new(33))).unwrap();
+/// assert_eq!(serialized, json!("33"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized.0.load(Ordering::Relaxed), 33);
+/// ```
+pub struct Atomic<F = crate::Id, O = AtomicSeqCst>(PhantomData<(F, O)>);
+
+impl<F, O> Atomic<F, O> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Memory ordering used by [`Atomic`] to load values
+pub trait LoadOrdering {
+    /// Ordering to load with
+    const ORDERING: Ordering;
+}
+
+/// Sequentially consistent ordering
+pub struct AtomicSeqCst;
+
+impl LoadOrdering for AtomicSeqCst {
+    const ORDERING: Ordering = Ordering::SeqCst;
+}
+
+/// Acquire ordering
+pub struct AtomicAcquire;
+
+impl LoadOrdering for AtomicAcquire {
+    const ORDERING: Ordering = Ordering::Acquire;
+}
+
+/// Relaxed ordering
+pub struct AtomicRelaxed;
+
+impl LoadOrdering for AtomicRelaxed {
+    const ORDERING: Ordering = Ordering::Relaxed;
+}
+
+macro_rules! impl_atomic {
+    ($($width:literal $atomic:ident $t:ty,)*) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl<F, O> SerializeWith<core::sync::atomic::$atomic> for Atomic<F, O>
+            where
+                F: SerializeWith<$t>,
+                O: LoadOrdering,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &core::sync::atomic::$atomic,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    F::serialize_with(&value.load(O::ORDERING), serializer)
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl<'de, F, O> DeserializeWith<'de, core::sync::atomic::$atomic> for Atomic<F, O>
+            where
+                F: DeserializeWith<'de, $t>,
+            {
+                fn deserialize_with<D>(
+                    deserializer: D,
+                ) -> Result<core::sync::atomic::$atomic, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    F::deserialize_with(deserializer).map(core::sync::atomic::$atomic::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic! {
+    "8" AtomicBool bool,
+    "8" AtomicI8 i8,
+    "8" AtomicU8 u8,
+    "16" AtomicI16 i16,
+    "16" AtomicU16 u16,
+    "32" AtomicI32 i32,
+    "32" AtomicU32 u32,
+    "64" AtomicI64 i64,
+    "64" AtomicU64 u64,
+    "ptr" AtomicIsize isize,
+    "ptr" AtomicUsize usize,
+}
+
+#[cfg(all(target_has_atomic = "32", test))]
+mod tests {
+    use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Deserialize, Serialize)]
+    struct Foo(#[serde(with = "crate::Atomic::<crate::Str>")] AtomicU32);
+
+    #[test]
+    fn atomic_adapter_roundtrips() {
+        let serialized = serde_json::to_value(Foo(AtomicU32::new(33))).unwrap();
+        assert_eq!(serialized, json!("33"));
+        let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+        assert_eq!(deserialized.0.load(Ordering::Relaxed), 33);
+    }
+
+    #[derive(Deserialize, Serialize)]
+    struct Relaxed(#[serde(with = "crate::Atomic::<crate::Id, crate::AtomicRelaxed>")] AtomicBool);
+
+    #[test]
+    fn atomic_adapter_with_ordering_roundtrips() {
+        let serialized = serde_json::to_value(Relaxed(AtomicBool::new(true))).unwrap();
+        assert_eq!(serialized, json!(true));
+        let deserialized = serde_json::from_value::<Relaxed>(serialized).unwrap();
+        assert!(deserialized.0.load(Ordering::Relaxed));
+    }
+}
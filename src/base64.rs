@@ -0,0 +1,330 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use ::base64::{
+    display::Base64Display,
+    engine::{general_purpose, GeneralPurpose},
+    Engine as _,
+};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, vec::Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize bytes as a base64 string
+///
+/// `C` selects the alphabet and padding. See [`Base64Config`] for the options. This adapter
+/// requires the `base64` feature.
+///
+/// This adapter supports the same types as [`Bytes`](crate::Bytes), except borrowed slices as
+/// the decoded bytes cannot borrow from the serialized string.
+///
+/// When used directly in `#[serde(with = ...)]`, the configuration must be spelled out, e.g.
+/// `Base64::<Base64Standard>`, as the default is not applied in this position.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::HumanOr::<sa::Base64, sa::Bytes>")]
+///     bytes: Vec<u8>,
+///     #[serde(with = "sa::Base64::<sa::Base64UrlSafeNoPad>")]
+///     key: [u8; 4],
+/// }
+///
+/// let foo = Foo { bytes: b"serdapt".to_vec(), key: [0xfb, 0xff, 0x00, 0x01] };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "bytes": "c2VyZGFwdA==", "key": "-_8AAQ" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct Base64<C = Base64Standard>(PhantomData<C>);
+
+impl<C> Base64<C> {
+    /// Serializes value as a base64 string
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value from a base64 string
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Base64 alphabet and padding used by [`Base64`]
+pub trait Base64Config {
+    /// Engine encoding and decoding base64
+    const ENGINE: GeneralPurpose;
+}
+
+/// Standard base64 alphabet with padding
+pub struct Base64Standard;
+
+impl Base64Config for Base64Standard {
+    const ENGINE: GeneralPurpose = general_purpose::STANDARD;
+}
+
+/// Standard base64 alphabet without padding
+pub struct Base64StandardNoPad;
+
+impl Base64Config for Base64StandardNoPad {
+    const ENGINE: GeneralPurpose = general_purpose::STANDARD_NO_PAD;
+}
+
+/// URL-safe base64 alphabet with padding
+pub struct Base64UrlSafe;
+
+impl Base64Config for Base64UrlSafe {
+    const ENGINE: GeneralPurpose = general_purpose::URL_SAFE;
+}
+
+/// URL-safe base64 alphabet without padding
+pub struct Base64UrlSafeNoPad;
+
+impl Base64Config for Base64UrlSafeNoPad {
+    const ENGINE: GeneralPurpose = general_purpose::URL_SAFE_NO_PAD;
+}
+
+impl<C, T> SerializeWith<T> for Base64<C>
+where
+    C: Base64Config,
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Base64Display::new(value.as_ref(), &C::ENGINE))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C> DeserializeWith<'de, Vec<u8>> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(VecVisitor::<C>(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C> DeserializeWith<'de, Box<[u8]>> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Box<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, C> DeserializeWith<'de, Rc<[u8]>> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Rc<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<'de, C> DeserializeWith<'de, alloc::sync::Arc<[u8]>> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<alloc::sync::Arc<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, 'a, C> DeserializeWith<'de, Cow<'a, [u8]>> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Cow<'a, [u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Cow::Owned)
+    }
+}
+
+impl<'de, C, const N: usize> DeserializeWith<'de, [u8; N]> for Base64<C>
+where
+    C: Base64Config,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ArrayVisitor::<C, N>(PhantomData))
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct VecVisitor<C>(PhantomData<C>);
+
+#[cfg(feature = "alloc")]
+impl<'de, C> Visitor<'de> for VecVisitor<C>
+where
+    C: Base64Config,
+{
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a base64 string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        C::ENGINE.decode(v).map_err(E::custom)
+    }
+}
+
+struct ArrayVisitor<C, const N: usize>(PhantomData<C>);
+
+impl<'de, C, const N: usize> Visitor<'de> for ArrayVisitor<C, N>
+where
+    C: Base64Config,
+{
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a base64 string of {N} bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // Full groups of 4 symbols are decoded straight into the array. The last group is decoded
+        // separately because the decoder's length estimate for it can exceed the array length.
+        let tail_len = match v.len() % 4 {
+            0 => v.len().min(4),
+            n => n,
+        };
+        let (head, tail) = v.split_at(v.len() - tail_len);
+        let mut bytes = [0u8; N];
+        let head_len = head.len() / 4 * 3;
+        let Some(out) = bytes.get_mut(..head_len) else {
+            return Err(E::invalid_length(head_len, &self));
+        };
+        if C::ENGINE.decode_slice(head, out).map_err(E::custom)? != head_len {
+            // Padding can only appear at the end
+            return Err(E::invalid_value(Unexpected::Bytes(v), &self));
+        }
+        let mut buf = [0u8; 3];
+        let n = C::ENGINE.decode_slice(tail, &mut buf).map_err(E::custom)?;
+        let len = head_len + n;
+        if let Some(out) = bytes.get_mut(head_len..len) {
+            out.copy_from_slice(&buf[..n]);
+        }
+        if len == N {
+            Ok(bytes)
+        } else {
+            Err(E::invalid_length(len, &self))
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct VecWrapper(#[serde(with = "crate::Base64::<crate::Base64Standard>")] Vec<u8>);
+
+    #[test]
+    fn base64_vec_roundtrips() {
+        check_serialization(VecWrapper(b"serdapt".to_vec()), json!("c2VyZGFwdA=="));
+    }
+
+    #[test]
+    fn empty_base64_vec_roundtrips() {
+        check_serialization(VecWrapper(Vec::new()), json!(""));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct UrlSafeWrapper(#[serde(with = "crate::Base64::<crate::Base64UrlSafeNoPad>")] Vec<u8>);
+
+    #[test]
+    fn url_safe_base64_roundtrips() {
+        check_serialization(UrlSafeWrapper(vec![0xfb, 0xff]), json!("-_8"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct ArrayWrapper<const N: usize>(
+        #[serde(with = "crate::Base64::<crate::Base64Standard>")] [u8; N],
+    );
+
+    #[test]
+    fn base64_array_roundtrips() {
+        check_serialization(ArrayWrapper(*b"serdapt"), json!("c2VyZGFwdA=="));
+    }
+
+    #[test]
+    fn base64_array_with_wrong_length_fails() {
+        serde_json::from_value::<ArrayWrapper<6>>(json!("c2VyZGFwdA==")).unwrap_err();
+        serde_json::from_value::<ArrayWrapper<8>>(json!("c2VyZGFwdA==")).unwrap_err();
+    }
+
+    #[test]
+    fn base64_array_with_padding_in_middle_fails() {
+        serde_json::from_value::<VecWrapper>(json!("AA==AAAA")).unwrap_err();
+        serde_json::from_value::<ArrayWrapper<4>>(json!("AA==AAAA")).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_base64_fails() {
+        serde_json::from_value::<VecWrapper>(json!("c2V*ZGFwdA==")).unwrap_err();
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Bytes, DeserializeWith, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use serde::{de::Error as _, ser::SerializeTuple, Deserialize, Deserializer, Serializer};
+
+/// Adapter to serialize a sequence of booleans as packed bits
+///
+/// The sequence is serialized as a tuple of its length as a `u64` and bytes serialized with
+/// [`Bytes`]. Each byte holds 8 booleans, with the first boolean in the least significant bit
+/// (LSB-first). The unused bits of the last byte are 0. When deserializing, the number of bytes
+/// must match the length and unused bits must be 0.
+///
+/// Any collection of `bool` that can be iterated by reference and collected from an iterator is
+/// supported.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Mask(#[serde(with = "sa::BitPack")] Vec<bool>);
+///
+/// let mask = Mask(vec![true, false, false, true, true, false, false, false, false, true]);
+/// let serialized = serde_json::to_value(&mask).unwrap();
+/// assert_eq!(serialized, json!([10, [0b0001_1001, 0b10]]));
+/// let deserialized = serde_json::from_value::<Mask>(serialized).unwrap();
+/// assert_eq!(deserialized, mask);
+/// ```
+pub struct BitPack;
+
+impl BitPack {
+    crate::adapter_methods!(pub);
+}
+
+impl<C> SerializeWith<C> for BitPack
+where
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = &'a bool>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut len = 0u64;
+        let mut bytes = Vec::new();
+        for &bit in container {
+            let i = len % 8;
+            if i == 0 {
+                bytes.push(0u8);
+            }
+            if bit {
+                *bytes.last_mut().unwrap() |= 1 << i;
+            }
+            len += 1;
+        }
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&len)?;
+        tuple.serialize_element(&WithEncoding::<&Bytes, _>::from(&bytes))?;
+        tuple.end()
+    }
+}
+
+impl<'de, C> DeserializeWith<'de, C> for BitPack
+where
+    C: FromIterator<bool>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (len, bytes) = <(u64, WithEncoding<Bytes, Vec<u8>>)>::deserialize(deserializer)?;
+        let bytes = bytes.into_inner();
+        let len = usize::try_from(len).map_err(D::Error::custom)?;
+        if bytes.len() != len.div_ceil(8) {
+            return Err(D::Error::invalid_length(
+                bytes.len(),
+                &"as many bytes as needed to hold the packed bits",
+            ));
+        }
+        let padding = bytes.len() * 8 - len;
+        if padding > 0 && bytes.last().is_some_and(|&b| b >> (8 - padding) != 0) {
+            return Err(D::Error::custom("unused bits of packed bits are not 0"));
+        }
+        Ok((0..len)
+            .map(|i| bytes[i / 8] & (1 << (i % 8)) != 0)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Mask(#[serde(with = "sa::BitPack")] Vec<bool>);
+
+    #[test]
+    fn bits_are_packed_lsb_first() {
+        check_serialization(
+            Mask(vec![
+                true, true, false, false, false, false, false, true, false,
+            ]),
+            json!([9, [0b1000_0011, 0]]),
+        );
+        check_serialization(Mask(vec![false; 8]), json!([8, [0]]));
+        check_serialization(Mask(vec![]), json!([0, []]));
+    }
+
+    #[test]
+    fn wrong_byte_count_fails() {
+        serde_json::from_value::<Mask>(json!([9, [0]])).unwrap_err();
+        serde_json::from_value::<Mask>(json!([1, [0, 0]])).unwrap_err();
+    }
+
+    #[test]
+    fn set_unused_bits_fail() {
+        serde_json::from_value::<Mask>(json!([1, [0b10]])).unwrap_err();
+    }
+
+    #[test]
+    fn large_mask_roundtrips_with_bincode() {
+        let original = Mask((0..1000).map(|i| i % 3 == 0).collect());
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(serialized.len(), 8 + 8 + 125);
+        let deserialized = bincode::deserialize::<Mask>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to serialize booleans as integers, only accepting 0 and 1
+///
+/// See [`BoolFromIntEncoding`] for details.
+pub type BoolFromInt = BoolFromIntEncoding<StrictBoolInt>;
+
+/// Adapter to serialize booleans as integers, accepting any nonzero integer as `true`
+///
+/// See [`BoolFromIntEncoding`] for details.
+pub type LenientBoolFromInt = BoolFromIntEncoding<LenientBoolInt>;
+
+/// Adapter for [`bool`] serialized as an integer
+///
+/// `false` is serialized as 0 and `true` as 1. The mode `M` selects which integers are accepted
+/// when deserializing:
+/// - [`StrictBoolInt`] only accepts 0 and 1.
+/// - [`LenientBoolInt`] accepts any integer, nonzero integers being `true`.
+///
+/// The [`BoolFromInt`] and [`LenientBoolFromInt`] aliases are provided for convenience.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::BoolFromInt")]
+///     strict: bool,
+///     #[serde(with = "sa::LenientBoolFromInt")]
+///     lenient: bool,
+/// }
+///
+/// let foo = Foo { strict: true, lenient: false };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "strict": 1, "lenient": 0 }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// let deserialized = serde_json::from_value::<Foo>(json!({ "strict": 0, "lenient": 7 })).unwrap();
+/// assert_eq!(deserialized, Foo { strict: false, lenient: true });
+/// assert!(serde_json::from_value::<Foo>(json!({ "strict": 7, "lenient": 7 })).is_err());
+/// ```
+pub struct BoolFromIntEncoding<M>(PhantomData<M>);
+
+impl<M> BoolFromIntEncoding<M> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Mode selecting which integers [`BoolFromIntEncoding`] accepts when deserializing
+pub trait BoolFromIntMode {
+    /// Whether integers other than 0 and 1 are accepted as `true`
+    const LENIENT: bool;
+}
+
+/// Mode only accepting 0 and 1
+pub struct StrictBoolInt;
+
+impl BoolFromIntMode for StrictBoolInt {
+    const LENIENT: bool = false;
+}
+
+/// Mode accepting any nonzero integer as `true`
+pub struct LenientBoolInt;
+
+impl BoolFromIntMode for LenientBoolInt {
+    const LENIENT: bool = true;
+}
+
+impl<M> SerializeWith<bool> for BoolFromIntEncoding<M> {
+    fn serialize_with<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*value))
+    }
+}
+
+impl<'de, M> DeserializeWith<'de, bool> for BoolFromIntEncoding<M>
+where
+    M: BoolFromIntMode,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u8(BoolFromIntVisitor::<M>::new())
+    }
+}
+
+struct BoolFromIntVisitor<M> {
+    _m: PhantomData<M>,
+}
+
+impl<M> BoolFromIntVisitor<M> {
+    fn new() -> Self {
+        Self { _m: PhantomData }
+    }
+}
+
+impl<M> BoolFromIntVisitor<M>
+where
+    M: BoolFromIntMode,
+{
+    fn convert<E, N>(n: N) -> Result<bool, E>
+    where
+        E: serde::de::Error,
+        N: Copy + PartialEq + From<bool> + fmt::Display,
+    {
+        if n == N::from(false) {
+            Ok(false)
+        } else if M::LENIENT || n == N::from(true) {
+            Ok(true)
+        } else {
+            Err(E::custom(format_args!("expected 0 or 1, got {n}")))
+        }
+    }
+}
+
+impl<'de, M> Visitor<'de> for BoolFromIntVisitor<M>
+where
+    M: BoolFromIntMode,
+{
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if M::LENIENT {
+            f.write_str("an integer")
+        } else {
+            f.write_str("0 or 1")
+        }
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Strict(#[serde(with = "sa::BoolFromInt")] bool);
+
+    #[test]
+    fn strict_roundtrips() {
+        check_serialization(Strict(false), json!(0));
+        check_serialization(Strict(true), json!(1));
+    }
+
+    #[test]
+    fn strict_rejects_other_integers() {
+        serde_json::from_value::<Strict>(json!(2)).unwrap_err();
+        serde_json::from_value::<Strict>(json!(-1)).unwrap_err();
+        serde_json::from_value::<Strict>(json!(true)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Lenient(#[serde(with = "sa::LenientBoolFromInt")] bool);
+
+    #[test]
+    fn lenient_accepts_nonzero_integers() {
+        check_serialization(Lenient(true), json!(1));
+        let deserialized = serde_json::from_value::<Lenient>(json!(-3)).unwrap();
+        assert_eq!(deserialized, Lenient(true));
+        let deserialized = serde_json::from_value::<Lenient>(json!(0)).unwrap();
+        assert_eq!(deserialized, Lenient(false));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Flags(#[serde(with = "sa::Seq::<sa::BoolFromInt>")] Vec<bool>);
+
+    #[test]
+    fn composes_with_seq() {
+        check_serialization(Flags(vec![true, false]), json!([1, 0]));
+    }
+
+    #[test]
+    fn roundtrips_with_bincode() {
+        let original = Flags(vec![true, false, true]);
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Flags>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Seq, SerializeWith, WithEncoding};
+use arrayvec::ArrayVec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error as _, IgnoredAny, SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Adapter for [`ArrayVec`] rejecting sequences exceeding its capacity
+///
+/// This adapter is available with the `arrayvec` feature. Items are serialized with `F` like
+/// [`Seq`] does. [`Seq`] can also deserialize an [`ArrayVec`], but it relies on the
+/// [`FromIterator`] implementation of [`ArrayVec`], which panics when there are too many items.
+/// This adapter instead pushes items until the [`ArrayVec`] is full and fails with an
+/// `invalid_length` error if more items are found. No heap allocation is involved.
+///
+/// # Example
+/// ```
+/// use arrayvec::ArrayVec;
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::BoundedArray::<sa::Str>")] ArrayVec<u8, 2>);
+///
+/// let foo = Foo(ArrayVec::from([1, 2]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!(["1", "2", "3"])).is_err());
+/// ```
+pub struct BoundedArray<F = crate::Id>(PhantomData<F>);
+
+impl<F> BoundedArray<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T, const N: usize> SerializeWith<ArrayVec<T, N>> for BoundedArray<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &ArrayVec<T, N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Seq::<F>::serialize_with(value.as_slice(), serializer)
+    }
+}
+
+impl<'de, F, T, const N: usize> DeserializeWith<'de, ArrayVec<T, N>> for BoundedArray<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<ArrayVec<T, N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BoundedArrayVisitor::<F, T, N>::new())
+    }
+}
+
+struct BoundedArrayVisitor<F, T, const N: usize> {
+    _f: PhantomData<F>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<F, T, const N: usize> BoundedArrayVisitor<F, T, N> {
+    fn new() -> Self {
+        BoundedArrayVisitor {
+            _f: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<'de, F, T, const N: usize> Visitor<'de> for BoundedArrayVisitor<F, T, N>
+where
+    F: DeserializeWith<'de, T>,
+{
+    type Value = ArrayVec<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at most {N} items")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        if let Some(len) = seq.size_hint().filter(|&len| len > N) {
+            return Err(A::Error::invalid_length(len, &self));
+        }
+        let mut items = ArrayVec::new();
+        while !items.is_full() {
+            match seq.next_element::<WithEncoding<F, T>>()? {
+                Some(x) => items.push(x.into_inner()),
+                None => return Ok(items),
+            }
+        }
+        // Check for an extra item without building it
+        match seq.next_element::<IgnoredAny>()? {
+            Some(_) => Err(A::Error::invalid_length(N + 1, &self)),
+            None => Ok(items),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use arrayvec::ArrayVec;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::BoundedArray::<sa::Str>")] ArrayVec<u32, 3>);
+
+    #[test]
+    fn partially_filled_array_roundtrips() {
+        check_serialization(Foo(ArrayVec::new()), json!([]));
+        check_serialization(Foo(ArrayVec::from_iter([1, 2])), json!(["1", "2"]));
+    }
+
+    #[test]
+    fn exactly_filled_array_roundtrips() {
+        check_serialization(Foo(ArrayVec::from([1, 2, 3])), json!(["1", "2", "3"]));
+    }
+
+    #[test]
+    fn overflow_is_rejected() {
+        let e = serde_json::from_str::<Foo>(r#"["1", "2", "3", "4"]"#).unwrap_err();
+        assert!(e.to_string().starts_with("invalid length 4"), "{e}");
+        let serialized = bincode::serialize(&["1", "2", "3", "4"][..]).unwrap();
+        let e = bincode::deserialize::<Foo>(&serialized).unwrap_err();
+        assert!(e.to_string().starts_with("invalid length 4"), "{e}");
+    }
+
+    #[test]
+    fn array_roundtrips_with_bincode() {
+        let original = Foo(ArrayVec::from_iter([7, 8]));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{size_hint, DeserializeWith, Seq, SerializeWith, WithEncoding};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error as _, IgnoredAny, SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Sequence adapter rejecting sequences with more than `MAX` items
+///
+/// This adapter behaves like [`Seq`] but deserialization fails as soon as more than `MAX` items
+/// are found, which bounds the memory used when deserializing untrusted input. Capacity reserved
+/// from the size hint of the input is also limited to `MAX` items.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::BoundedSeq::<2, sa::Str>")] Vec<i32>);
+///
+/// let foo = Foo(vec![1, 2]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!(["1", "2", "3"])).is_err());
+/// # }
+/// ```
+pub struct BoundedSeq<const MAX: usize, F = crate::Id>(PhantomData<F>);
+
+impl<const MAX: usize, F> BoundedSeq<MAX, F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<const MAX: usize, F, C> SerializeWith<C> for BoundedSeq<MAX, F>
+where
+    Seq<F>: SerializeWith<C>,
+    C: ?Sized,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        Seq::<F>::serialize_with(container, serializer)
+    }
+}
+
+impl<'de, const MAX: usize, F, C> DeserializeWith<'de, C> for BoundedSeq<MAX, F>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BoundedSeqVisitor::<MAX, F, C>::new())
+    }
+}
+
+struct BoundedSeqVisitor<const MAX: usize, F, C> {
+    _f: PhantomData<F>,
+    _c: PhantomData<fn() -> C>,
+}
+
+impl<const MAX: usize, F, C> BoundedSeqVisitor<MAX, F, C> {
+    fn new() -> Self {
+        BoundedSeqVisitor {
+            _f: PhantomData,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<'de, const MAX: usize, F, C> Visitor<'de> for BoundedSeqVisitor<MAX, F, C>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of at most {MAX} items")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let hint = seq.size_hint();
+        if let Some(len) = hint.filter(|&len| len > MAX) {
+            return Err(A::Error::invalid_length(len, &self));
+        }
+        let mut count = 0;
+        let items = core::iter::from_fn(|| {
+            if count == MAX {
+                // Check for an extra item without building it
+                return match seq.next_element::<IgnoredAny>() {
+                    Ok(Some(_)) => Some(Err(A::Error::invalid_length(MAX + 1, &self))),
+                    Ok(None) => None,
+                    Err(e) => Some(Err(e)),
+                };
+            }
+            count += 1;
+            seq.next_element::<WithEncoding<F, C::Item>>()
+                .map(|x| x.map(WithEncoding::into_inner))
+                .transpose()
+        });
+        size_hint::collect(hint, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use crate::test_utils::check_serialization;
+    use crate::{self as sa, DeserializeWith};
+    #[cfg(feature = "alloc")]
+    use alloc::{vec, vec::Vec};
+    use serde::de::{
+        value::{Error, SeqDeserializer},
+        Error as _,
+    };
+    #[cfg(feature = "alloc")]
+    use serde::{Deserialize, Serialize};
+    #[cfg(feature = "alloc")]
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq)]
+    struct Total(u32);
+
+    impl FromIterator<u32> for Total {
+        fn from_iter<I: IntoIterator<Item = u32>>(items: I) -> Self {
+            Total(items.into_iter().sum())
+        }
+    }
+
+    impl IntoIterator for Total {
+        type Item = u32;
+        type IntoIter = core::iter::Once<u32>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            core::iter::once(self.0)
+        }
+    }
+
+    #[test]
+    fn items_are_collected_up_to_bound() {
+        let items = SeqDeserializer::<_, Error>::new([1u32, 2, 3].into_iter());
+        let total: Total = sa::BoundedSeq::<3>::deserialize_with(items).unwrap();
+        assert_eq!(total, Total(6));
+    }
+
+    #[test]
+    fn items_beyond_bound_are_rejected() {
+        let items = SeqDeserializer::<_, Error>::new([1u32, 2, 3, 4].into_iter());
+        let e = <sa::BoundedSeq<3> as DeserializeWith<Total>>::deserialize_with(items).unwrap_err();
+        assert_eq!(
+            e,
+            Error::invalid_length(4, &"a sequence of at most 3 items")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::BoundedSeq::<3>")] Vec<u32>);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequence_within_bound_roundtrips() {
+        check_serialization(Foo(vec![]), json!([]));
+        check_serialization(Foo(vec![1, 2, 3]), json!([1, 2, 3]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sequence_exceeding_bound_is_rejected() {
+        serde_json::from_value::<Foo>(json!([1, 2, 3, 4])).unwrap_err();
+        serde_json::from_str::<Foo>("[1, 2, 3, 4]").unwrap_err();
+        let serialized = bincode::serialize(&vec![1u32, 2, 3, 4]).unwrap();
+        bincode::deserialize::<Foo>(&serialized).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn invalid_item_fails() {
+        serde_json::from_value::<Foo>(json!([1, "2"])).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Empty(#[serde(with = "sa::BoundedSeq::<0, sa::Str>")] Vec<u32>);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zero_bound_only_accepts_empty_sequence() {
+        check_serialization(Empty(Vec::new()), json!([]));
+        serde_json::from_value::<Empty>(json!(["1"])).unwrap_err();
+    }
+}
@@ -1,8 +1,17 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
 use crate::{DeserializeWith, SerializeWith};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::{borrow::Cow, boxed::Box, rc::Rc, string::String, vec::Vec};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    collections::VecDeque,
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
 use core::{
     fmt::{self, Display},
     marker::PhantomData,
@@ -12,7 +21,8 @@ use serde::{de::Visitor, Deserializer, Serializer};
 /// Adapter for contiguous byte sequences
 ///
 /// If a type is not supported by this adapter and is convertible from `Vec<u8>`, the [`ByteVec`]
-/// adapter should be used instead.
+/// adapter should be used instead. If bytes must be serialized as a plain sequence of numbers
+/// whatever the format, the [`ByteSeq`] adapter should be used instead.
 ///
 /// This allows optimized handling of byte sequences when serializing. This is similar to
 /// [`serde_bytes`](https://docs.rs/serde_bytes). Practically, this adapter allows serialization of
@@ -22,6 +32,11 @@ use serde::{de::Visitor, Deserializer, Serializer};
 /// This adapter always serializes as a serde variable-length byte sequence, even if the collection
 /// type to serialize has a statically known length.
 ///
+/// Serialization supports byte slices, arrays, strings and the standard containers and smart
+/// pointers holding them. Other types exposing their bytes with [`AsRef<[u8]>`](AsRef) can be
+/// serialized with [`ByteVec`]. The bytes of a `VecDeque<u8>` are serialized in logical order, which
+/// requires copying them to a temporary buffer if they wrap around the end of the ring buffer.
+///
 /// # Example
 /// ```
 /// # #[cfg(feature = "std")] {
@@ -57,12 +72,107 @@ impl Bytes {
     }
 }
 
-impl<T> SerializeWith<T> for Bytes
+macro_rules! impl_serialize_as_ref {
+    ($($(#[$attr:meta])* $t:ty),*) => {
+        $(
+            $(#[$attr])*
+            impl SerializeWith<$t> for Bytes {
+                fn serialize_with<S: Serializer>(
+                    value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_bytes(AsRef::<[u8]>::as_ref(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_as_ref!(
+    [u8],
+    str,
+    #[cfg(feature = "alloc")]
+    Vec<u8>,
+    #[cfg(feature = "alloc")]
+    String
+);
+
+impl<const N: usize> SerializeWith<[u8; N]> for Bytes {
+    fn serialize_with<S: Serializer>(value: &[u8; N], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(value)
+    }
+}
+
+impl<T> SerializeWith<&T> for Bytes
 where
-    T: AsRef<[u8]> + ?Sized,
+    T: ?Sized,
+    Bytes: SerializeWith<T>,
 {
-    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_bytes(value.as_ref())
+    fn serialize_with<S: Serializer>(value: &&T, serializer: S) -> Result<S::Ok, S::Error> {
+        Bytes::serialize_with(*value, serializer)
+    }
+}
+
+impl<T> SerializeWith<&mut T> for Bytes
+where
+    T: ?Sized,
+    Bytes: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &&mut T, serializer: S) -> Result<S::Ok, S::Error> {
+        Bytes::serialize_with(&**value, serializer)
+    }
+}
+
+macro_rules! impl_serialize_pointer {
+    ($($(#[$attr:meta])* $p:ident),*) => {
+        $(
+            $(#[$attr])*
+            impl<T> SerializeWith<$p<T>> for Bytes
+            where
+                T: ?Sized,
+                Bytes: SerializeWith<T>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$p<T>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Bytes::serialize_with(&**value, serializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_pointer!(
+    #[cfg(feature = "alloc")]
+    Box,
+    #[cfg(feature = "alloc")]
+    Rc,
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    Arc
+);
+
+#[cfg(feature = "alloc")]
+impl<T> SerializeWith<Cow<'_, T>> for Bytes
+where
+    T: ToOwned + ?Sized,
+    Bytes: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &Cow<'_, T>, serializer: S) -> Result<S::Ok, S::Error> {
+        Bytes::serialize_with(&**value, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SerializeWith<VecDeque<u8>> for Bytes {
+    fn serialize_with<S: Serializer>(
+        value: &VecDeque<u8>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value.as_slices() {
+            (bytes, []) => serializer.serialize_bytes(bytes),
+            (front, back) => serializer.serialize_bytes(&[front, back].concat()),
+        }
     }
 }
 
@@ -96,6 +206,16 @@ impl<'de> DeserializeWith<'de, Rc<[u8]>> for Bytes {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'de> DeserializeWith<'de, VecDeque<u8>> for Bytes {
+    fn deserialize_with<D>(deserializer: D) -> Result<VecDeque<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Bytes as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
 #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 impl<'de> DeserializeWith<'de, alloc::sync::Arc<[u8]>> for Bytes {
     fn deserialize_with<D>(deserializer: D) -> Result<alloc::sync::Arc<[u8]>, D::Error>
@@ -391,7 +511,7 @@ where
     T: AsRef<[u8]> + ?Sized,
 {
     fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
-        Bytes::serialize_with(value, serializer)
+        serializer.serialize_bytes(value.as_ref())
     }
 }
 
@@ -410,10 +530,170 @@ where
     }
 }
 
+/// Adapter to serialize bytes as a sequence of numbers
+///
+/// This is the counterpart of [`Bytes`] for formats or schemas that expect bytes as a plain
+/// sequence. [`Bytes`] serializes through [`Serializer::serialize_bytes`], which formats may
+/// represent as they wish, e.g. as a binary blob or a string. This adapter instead always
+/// serializes a sequence of `u8` with [`Serializer::collect_seq`], as serde does by default for
+/// `Vec<u8>`, but for any type exposing its bytes with [`AsRef<[u8]>`](AsRef).
+///
+/// Deserializing requires the `alloc` feature and accepts both a sequence and bytes, as well as a
+/// string whose UTF-8 encoding is used. Any type convertible from `Vec<u8>` can be deserialized.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::rc::Rc;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::ByteSeq")] Rc<[u8]>);
+///
+/// let foo = Foo(Rc::from([1, 2, 3]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([1, 2, 3]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct ByteSeq;
+
+impl ByteSeq {
+    /// Serializes value as a sequence of bytes
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value from a sequence of bytes
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<T> SerializeWith<T> for ByteSeq
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.as_ref())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeWith<'de, T> for ByteSeq
+where
+    T: TryFrom<Vec<u8>>,
+    T::Error: Display,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserializer.deserialize_seq(VecVisitor)?;
+        bytes.try_into().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Adapter for byte sequences of exactly `N` bytes
+///
+/// This adapter behaves like [`Bytes`], except that the number of bytes must be `N`. Serializing or
+/// deserializing a different number of bytes results in an error. This is useful for fixed-size
+/// data, such as hashes, stored in heap buffers like `Vec<u8>` or `Box<[u8]>`. Arrays already
+/// have their length checked by [`Bytes`].
+///
+/// Any type supported by [`Bytes`] for deserialization and implementing [`AsRef<[u8]>`](AsRef) is
+/// supported.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::FixedBytes::<4>")] Vec<u8>);
+///
+/// let foo = Foo(vec![1, 2, 3, 4]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([1, 2, 3, 4]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!([1, 2, 3])).is_err());
+/// # }
+/// ```
+pub struct FixedBytes<const N: usize>;
+
+impl<const N: usize> FixedBytes<N> {
+    /// Serializes value as bytes
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value from bytes
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<const N: usize, T> SerializeWith<T> for FixedBytes<N>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = value.as_ref();
+        if bytes.len() != N {
+            return Err(serde::ser::Error::custom(format_args!(
+                "expected {N} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+impl<'de, const N: usize, T> DeserializeWith<'de, T> for FixedBytes<N>
+where
+    Bytes: DeserializeWith<'de, T>,
+    T: AsRef<[u8]>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Bytes::deserialize_with(deserializer)?;
+        match value.as_ref().len() {
+            len if len == N => Ok(value),
+            len => Err(serde::de::Error::invalid_length(len, &ArrayVisitor::<N>)),
+        }
+    }
+}
+
 #[cfg(all(feature = "alloc", test))]
 mod tests {
     use crate::test_utils::check_serialization;
-    use alloc::{borrow::Cow, boxed::Box, rc::Rc, vec, vec::Vec};
+    use alloc::{borrow::Cow, boxed::Box, collections::VecDeque, rc::Rc, vec, vec::Vec};
     use core::fmt::Debug;
     use serde::{Deserialize, Serialize};
     use serde_json::json;
@@ -507,4 +787,78 @@ mod tests {
             panic!("Expected Cow::Borrowed");
         };
     }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct DequeWrapper(#[serde(with = "crate::Bytes")] VecDeque<u8>);
+
+    #[test]
+    fn byte_deque_roundtrips() {
+        check_serialization(DequeWrapper(VecDeque::from([1, 2, 3])), json!([1, 2, 3]));
+    }
+
+    #[test]
+    fn wrapped_byte_deque_roundtrips_in_logical_order() {
+        let mut bytes = VecDeque::with_capacity(4);
+        bytes.extend([3, 4]);
+        bytes.push_front(2);
+        bytes.push_front(1);
+        assert!(!bytes.as_slices().1.is_empty());
+        let original = DequeWrapper(bytes);
+        check_serialization(DequeWrapper(original.0.clone()), json!([1, 2, 3, 4]));
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(
+            serialized,
+            bincode::serialize(&VecWrapper(vec![1, 2, 3, 4])).unwrap()
+        );
+        let deserialized = bincode::deserialize::<DequeWrapper>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct SeqWrapper(#[serde(with = "crate::ByteSeq")] Box<[u8]>);
+
+    #[test]
+    fn byte_seq_roundtrips() {
+        check_serialization(SeqWrapper(Box::from([1, 2, 3])), json!([1, 2, 3]));
+        check_serialization(SeqWrapper(Box::from([])), json!([]));
+    }
+
+    #[test]
+    fn byte_seq_accepts_bytes() {
+        use serde::de::value::{BytesDeserializer, Error};
+
+        let deserializer = BytesDeserializer::<Error>::new(&[4, 5]);
+        let deserialized: Box<[u8]> = crate::ByteSeq::deserialize(deserializer).unwrap();
+        assert_eq!(*deserialized, [4, 5]);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Hash(#[serde(with = "crate::FixedBytes::<4>")] Vec<u8>);
+
+    #[test]
+    fn fixed_bytes_roundtrip() {
+        check_serialization(Hash(vec![1, 2, 3, 4]), json!([1, 2, 3, 4]));
+        let original = Hash(vec![5, 6, 7, 8]);
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Hash>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn fixed_bytes_with_wrong_length_fail() {
+        serde_json::from_value::<Hash>(json!([1, 2, 3])).unwrap_err();
+        serde_json::from_value::<Hash>(json!([1, 2, 3, 4, 5])).unwrap_err();
+        let serialized = bincode::serialize(&VecWrapper(vec![1, 2])).unwrap();
+        bincode::deserialize::<Hash>(&serialized).unwrap_err();
+        serde_json::to_value(Hash(vec![1, 2])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct BoxedHash(#[serde(with = "crate::FixedBytes::<2>")] Box<[u8]>);
+
+    #[test]
+    fn boxed_fixed_bytes_roundtrip() {
+        check_serialization(BoxedHash(Box::from([1, 2])), json!([1, 2]));
+        serde_json::from_value::<BoxedHash>(json!([1])).unwrap_err();
+    }
 }
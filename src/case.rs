@@ -0,0 +1,198 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use alloc::string::String;
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to convert text to lowercase
+///
+/// When serializing, the text is converted to lowercase and the result is serialized with `F`.
+/// When deserializing, the value is deserialized with `F` and its text is converted to lowercase.
+/// Supported types are those that convert to and from [`String`].
+///
+/// Case is converted according to `M`, which defaults to [`UnicodeCase`]. [`AsciiCase`] only
+/// converts ASCII letters and leaves other characters untouched.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Lowercase::<sa::Id>")] String);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!("ÉTÉ")).unwrap();
+/// assert_eq!(deserialized, Foo("été".into()));
+/// let serialized = serde_json::to_value(Foo("Été".into())).unwrap();
+/// assert_eq!(serialized, json!("été"));
+/// # }
+/// ```
+pub struct Lowercase<F = crate::Id, M = UnicodeCase>(PhantomData<(F, M)>);
+
+impl<F, M> Lowercase<F, M> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, M, T> SerializeWith<T> for Lowercase<F, M>
+where
+    F: SerializeWith<T>,
+    M: CaseFolding,
+    T: AsRef<str> + From<String>,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&T::from(M::to_lowercase(value.as_ref())), serializer)
+    }
+}
+
+impl<'de, F, M, T> DeserializeWith<'de, T> for Lowercase<F, M>
+where
+    F: DeserializeWith<'de, T>,
+    M: CaseFolding,
+    T: Into<String> + From<String>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = F::deserialize_with(deserializer)?.into();
+        Ok(T::from(M::to_lowercase(&s)))
+    }
+}
+
+/// Adapter to convert text to uppercase
+///
+/// When serializing, the text is converted to uppercase and the result is serialized with `F`.
+/// When deserializing, the value is deserialized with `F` and its text is converted to uppercase.
+/// Supported types are those that convert to and from [`String`].
+///
+/// Case is converted according to `M`, which defaults to [`UnicodeCase`]. [`AsciiCase`] only
+/// converts ASCII letters and leaves other characters untouched.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Uppercase::<sa::Id, sa::AsciiCase>")] String);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!("été")).unwrap();
+/// assert_eq!(deserialized, Foo("éTé".into()));
+/// # }
+/// ```
+pub struct Uppercase<F = crate::Id, M = UnicodeCase>(PhantomData<(F, M)>);
+
+impl<F, M> Uppercase<F, M> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, M, T> SerializeWith<T> for Uppercase<F, M>
+where
+    F: SerializeWith<T>,
+    M: CaseFolding,
+    T: AsRef<str> + From<String>,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&T::from(M::to_uppercase(value.as_ref())), serializer)
+    }
+}
+
+impl<'de, F, M, T> DeserializeWith<'de, T> for Uppercase<F, M>
+where
+    F: DeserializeWith<'de, T>,
+    M: CaseFolding,
+    T: Into<String> + From<String>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = F::deserialize_with(deserializer)?.into();
+        Ok(T::from(M::to_uppercase(&s)))
+    }
+}
+
+/// Case conversion used by [`Lowercase`] and [`Uppercase`]
+pub trait CaseFolding {
+    /// Converts text to lowercase
+    fn to_lowercase(s: &str) -> String;
+
+    /// Converts text to uppercase
+    fn to_uppercase(s: &str) -> String;
+}
+
+/// Case conversion following Unicode rules
+///
+/// See [`str::to_lowercase`] and [`str::to_uppercase`].
+pub struct UnicodeCase;
+
+impl CaseFolding for UnicodeCase {
+    fn to_lowercase(s: &str) -> String {
+        s.to_lowercase()
+    }
+
+    fn to_uppercase(s: &str) -> String {
+        s.to_uppercase()
+    }
+}
+
+/// Case conversion of ASCII letters only
+///
+/// See [`str::to_ascii_lowercase`] and [`str::to_ascii_uppercase`].
+pub struct AsciiCase;
+
+impl CaseFolding for AsciiCase {
+    fn to_lowercase(s: &str) -> String {
+        s.to_ascii_lowercase()
+    }
+
+    fn to_uppercase(s: &str) -> String {
+        s.to_ascii_uppercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{boxed::Box, string::String};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Lower(#[serde(with = "sa::Lowercase::<sa::Id>")] String);
+
+    #[test]
+    fn lowercase_normalizes_both_ways() {
+        check_serialization(Lower("foo".into()), json!("foo"));
+        assert_eq!(
+            serde_json::to_value(Lower("FoO".into())).unwrap(),
+            json!("foo")
+        );
+        let deserialized = serde_json::from_value::<Lower>(json!("FoO")).unwrap();
+        assert_eq!(deserialized, Lower("foo".into()));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Upper(#[serde(with = "sa::Uppercase::<sa::Id>")] Box<str>);
+
+    #[test]
+    fn uppercase_supports_boxed_str() {
+        check_serialization(Upper("FOO".into()), json!("FOO"));
+        let deserialized = serde_json::from_value::<Upper>(json!("straße")).unwrap();
+        assert_eq!(deserialized, Upper("STRASSE".into()));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct AsciiUpper(#[serde(with = "sa::Uppercase::<sa::Id, sa::AsciiCase>")] String);
+
+    #[test]
+    fn ascii_folding_leaves_other_characters() {
+        let deserialized = serde_json::from_value::<AsciiUpper>(json!("straße")).unwrap();
+        assert_eq!(deserialized, AsciiUpper("STRAßE".into()));
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize a [`char`] as a one-character string
+///
+/// See [`CharEncoding`] for details.
+pub type Char = CharEncoding<TextOnly>;
+
+/// Adapter to serialize a [`char`] as a one-character string, also accepting code points
+///
+/// See [`CharEncoding`] for details.
+pub type CharOrCodePoint = CharEncoding<AllowCodePoint>;
+
+/// Adapter for [`char`] serialized as a one-character string
+///
+/// Serialization does not allocate. Deserialization fails with an
+/// [`invalid_length`](serde::de::Error::invalid_length) error if the string does not contain
+/// exactly one character. The mode `M` selects what else is accepted when deserializing:
+/// - [`TextOnly`] only accepts strings and characters.
+/// - [`AllowCodePoint`] also accepts integers that are valid Unicode scalar values. Since an
+///   integer can only be told apart from a string when the format is self-describing, this mode
+///   does not support other formats.
+///
+/// The [`Char`] and [`CharOrCodePoint`] aliases are provided for convenience.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Char")]
+///     text: char,
+///     #[serde(with = "sa::CharOrCodePoint")]
+///     code: char,
+/// }
+///
+/// let foo = Foo { text: 'é', code: 'a' };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "text": "é", "code": "a" }));
+/// let deserialized = serde_json::from_value::<Foo>(json!({ "text": "é", "code": 97 })).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!({ "text": "ab", "code": "a" })).is_err());
+/// ```
+pub struct CharEncoding<M>(PhantomData<M>);
+
+impl<M> CharEncoding<M> {
+    crate::adapter_methods!(pub);
+}
+
+/// Mode selecting what [`CharEncoding`] accepts when deserializing
+pub trait CharMode {
+    /// Whether integers are accepted as Unicode code points
+    const ALLOW_CODE_POINT: bool;
+}
+
+/// Mode only accepting strings and characters
+pub struct TextOnly;
+
+impl CharMode for TextOnly {
+    const ALLOW_CODE_POINT: bool = false;
+}
+
+/// Mode also accepting integers as Unicode code points
+pub struct AllowCodePoint;
+
+impl CharMode for AllowCodePoint {
+    const ALLOW_CODE_POINT: bool = true;
+}
+
+impl<M> SerializeWith<char> for CharEncoding<M> {
+    fn serialize_with<S: Serializer>(value: &char, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<'de, M> DeserializeWith<'de, char> for CharEncoding<M>
+where
+    M: CharMode,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<char, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if M::ALLOW_CODE_POINT {
+            deserializer.deserialize_any(CharVisitor::<M>::new())
+        } else {
+            deserializer.deserialize_str(CharVisitor::<M>::new())
+        }
+    }
+}
+
+struct CharVisitor<M>(PhantomData<M>);
+
+impl<M> CharVisitor<M> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M> Visitor<'_> for CharVisitor<M>
+where
+    M: CharMode,
+{
+    type Value = char;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if M::ALLOW_CODE_POINT {
+            f.write_str("a one-character string or a Unicode code point")
+        } else {
+            f.write_str("a one-character string")
+        }
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut chars = v.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(E::invalid_length(v.chars().count(), &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if !M::ALLOW_CODE_POINT {
+            return Err(E::invalid_type(Unexpected::Unsigned(v), &self));
+        }
+        u32::try_from(v)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) if M::ALLOW_CODE_POINT => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+            Err(_) => Err(E::invalid_type(Unexpected::Signed(v), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::Char")] char);
+
+    #[test]
+    fn char_roundtrips_as_string() {
+        check_serialization(Text('a'), json!("a"));
+        check_serialization(Text('😀'), json!("😀"));
+    }
+
+    #[test]
+    fn string_must_have_one_char() {
+        serde_json::from_value::<Text>(json!("")).unwrap_err();
+        serde_json::from_value::<Text>(json!("ab")).unwrap_err();
+        let e = serde_json::from_value::<Text>(json!("abc")).unwrap_err();
+        assert!(e.to_string().starts_with("invalid length 3"));
+    }
+
+    #[test]
+    fn text_only_rejects_code_point() {
+        serde_json::from_value::<Text>(json!(97)).unwrap_err();
+    }
+
+    #[test]
+    fn char_roundtrips_with_bincode() {
+        let serialized = bincode::serialize(&Text('é')).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Text>(&serialized).unwrap(),
+            Text('é')
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Code(#[serde(with = "sa::CharOrCodePoint")] char);
+
+    #[test]
+    fn code_point_is_accepted() {
+        check_serialization(Code('a'), json!("a"));
+        let deserialized = serde_json::from_value::<Code>(json!(0x1f600)).unwrap();
+        assert_eq!(deserialized, Code('😀'));
+    }
+
+    #[test]
+    fn invalid_code_point_is_rejected() {
+        serde_json::from_value::<Code>(json!(0xd800)).unwrap_err();
+        serde_json::from_value::<Code>(json!(0x110000)).unwrap_err();
+        serde_json::from_value::<Code>(json!(-1)).unwrap_err();
+    }
+}
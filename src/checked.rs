@@ -0,0 +1,157 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Bytes, DeserializeWith, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::{
+    de::Error as _, ser::Error as _, ser::SerializeTuple, Deserialize, Deserializer, Serializer,
+};
+
+/// Adapter to serialize a value along with a checksum of its serialized form
+///
+/// This adapter is available with the `json` feature. The value is serialized to JSON with `F`
+/// using `serde_json`, and the resulting bytes are serialized as a tuple of the bytes and a `u32`
+/// checksum of the bytes computed with `H`. When deserializing, the checksum is computed again from
+/// the bytes and deserialization fails if it does not match. Otherwise the bytes are parsed as JSON
+/// with `F`. This helps detect corrupted data.
+///
+/// The value is encoded twice and the JSON bytes are buffered, which takes time and space.
+/// Deserialization always parses from an owned buffer, so the value cannot borrow from the input.
+///
+/// The `Crc32` checksum is available with the `crc32` feature.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "json")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct Sum;
+///
+/// impl sa::Checksum for Sum {
+///     fn checksum(bytes: &[u8]) -> u32 {
+///         bytes.iter().map(|&b| u32::from(b)).sum()
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Checked::<Sum>")] u32);
+///
+/// let foo = Foo(12);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([b"12", u32::from(b'1' + b'2')]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!([b"13", 99])).is_err());
+/// # }
+/// ```
+pub struct Checked<H, F = crate::Id>(PhantomData<(H, F)>);
+
+impl<H, F> Checked<H, F> {
+    crate::adapter_methods!(pub);
+}
+
+/// Checksum algorithm used by [`Checked`]
+pub trait Checksum {
+    /// Computes the checksum of bytes
+    fn checksum(bytes: &[u8]) -> u32;
+}
+
+impl<H, F, T> SerializeWith<T> for Checked<H, F>
+where
+    H: Checksum,
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes =
+            serde_json::to_vec(&WithEncoding::<&F, &T>::from(value)).map_err(S::Error::custom)?;
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&WithEncoding::<Bytes, _>::from(&bytes))?;
+        tuple.serialize_element(&H::checksum(&bytes))?;
+        tuple.end()
+    }
+}
+
+impl<'de, H, F, T> DeserializeWith<'de, T> for Checked<H, F>
+where
+    H: Checksum,
+    F: for<'a> DeserializeWith<'a, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (bytes, expected) = <(WithEncoding<Bytes, Vec<u8>>, u32)>::deserialize(deserializer)?;
+        let bytes = bytes.into_inner();
+        let actual = H::checksum(&bytes);
+        if actual != expected {
+            return Err(D::Error::custom(format_args!(
+                "checksum mismatch: expected {expected:#010x}, got {actual:#010x}"
+            )));
+        }
+        serde_json::from_slice::<WithEncoding<F, T>>(&bytes)
+            .map(WithEncoding::into_inner)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// CRC-32 checksum
+///
+/// This requires the `crc32` feature.
+#[cfg(feature = "crc32")]
+pub struct Crc32;
+
+#[cfg(feature = "crc32")]
+impl Checksum for Crc32 {
+    fn checksum(bytes: &[u8]) -> u32 {
+        crc32fast::hash(bytes)
+    }
+}
+
+#[cfg(all(feature = "crc32", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{string::String, vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Checked::<sa::Crc32, sa::Seq<sa::Str>>")] Vec<u32>);
+
+    #[test]
+    fn serialized_value_roundtrips_with_checksum() {
+        let json = r#"["1","2"]"#;
+        check_serialization(
+            Foo(vec![1, 2]),
+            json!([json.as_bytes(), crc32fast::hash(json.as_bytes())]),
+        );
+    }
+
+    #[test]
+    fn corrupted_payload_is_rejected() {
+        let json = br#"["1","3"]"#;
+        let e = serde_json::from_value::<Foo>(json!([json, crc32fast::hash(br#"["1","2"]"#)]))
+            .unwrap_err();
+        assert!(e.to_string().starts_with("checksum mismatch"));
+    }
+
+    #[test]
+    fn invalid_payload_with_valid_checksum_is_rejected() {
+        let json = br#"["1",2]"#;
+        serde_json::from_value::<Foo>(json!([json, crc32fast::hash(json)])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Name(#[serde(with = "sa::Checked::<sa::Crc32>")] String);
+
+    #[test]
+    fn payload_roundtrips_with_bincode() {
+        let original = Name("x".repeat(100));
+        let mut serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(bincode::deserialize::<Name>(&serialized).unwrap(), original);
+        serialized[10] = b'y';
+        bincode::deserialize::<Name>(&serialized).unwrap_err();
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Seq, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, SeqAccess, Visitor},
+    ser::Error as _,
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize a sequence as a sequence of chunks of `N` items
+///
+/// Each chunk is serialized as a sequence of `N` items, except the last chunk which may be
+/// shorter. Items are serialized with `F`. When deserializing, chunks are concatenated back into
+/// a flat collection, and an error is returned if a chunk other than the last one does not have
+/// `N` items, or if any chunk is empty or longer than `N`.
+///
+/// `N` must not be zero.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::Chunks::<4, serdapt::Id>")] Vec<i32>);
+///
+/// let foo = Foo(vec![1, 2, 3, 4, 5]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([[1, 2, 3, 4], [5]]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct Chunks<const N: usize, F>(PhantomData<F>);
+
+impl<const N: usize, F> Chunks<N, F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<const N: usize, F, T> SerializeWith<[T]> for Chunks<N, F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+        if N == 0 {
+            return Err(S::Error::custom("chunk size must not be zero"));
+        }
+        serializer.collect_seq(value.chunks(N).map(WithEncoding::<&Seq<F>, _>::from))
+    }
+}
+
+impl<const N: usize, F, T> SerializeWith<Vec<T>> for Chunks<N, F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &Vec<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        <Self as SerializeWith<[T]>>::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, const N: usize, F, T> DeserializeWith<'de, Vec<T>> for Chunks<N, F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if N == 0 {
+            return Err(serde::de::Error::custom("chunk size must not be zero"));
+        }
+        deserializer.deserialize_seq(ChunksVisitor::<N, F, T>::new())
+    }
+}
+
+struct ChunksVisitor<const N: usize, F, T> {
+    _f: PhantomData<F>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<const N: usize, F, T> ChunksVisitor<N, F, T> {
+    fn new() -> Self {
+        Self {
+            _f: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<'de, const N: usize, F, T> Visitor<'de> for ChunksVisitor<N, F, T>
+where
+    F: DeserializeWith<'de, T>,
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of chunks of {N} items")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::new();
+        let mut last_len = N;
+        while let Some(len) = seq.next_element_seed(ChunkSeed::<N, F, T>::new(&mut items))? {
+            if last_len != N {
+                return Err(serde::de::Error::invalid_length(
+                    last_len,
+                    &ExpectedChunkLength::<N>,
+                ));
+            }
+            last_len = len;
+        }
+        Ok(items)
+    }
+}
+
+struct ChunkSeed<'a, const N: usize, F, T> {
+    items: &'a mut Vec<T>,
+    _f: PhantomData<F>,
+}
+
+impl<'a, const N: usize, F, T> ChunkSeed<'a, N, F, T> {
+    fn new(items: &'a mut Vec<T>) -> Self {
+        Self {
+            items,
+            _f: PhantomData,
+        }
+    }
+}
+
+impl<'de, const N: usize, F, T> DeserializeSeed<'de> for ChunkSeed<'_, N, F, T>
+where
+    F: DeserializeWith<'de, T>,
+{
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const N: usize, F, T> Visitor<'de> for ChunkSeed<'_, N, F, T>
+where
+    F: DeserializeWith<'de, T>,
+{
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        serde::de::Expected::fmt(&ExpectedChunkLength::<N>, f)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut len = 0;
+        while let Some(x) = seq.next_element::<WithEncoding<F, T>>()? {
+            if len == N {
+                return Err(serde::de::Error::invalid_length(
+                    len + 1,
+                    &ExpectedChunkLength::<N>,
+                ));
+            }
+            self.items.push(x.into_inner());
+            len += 1;
+        }
+        if len == 0 {
+            return Err(serde::de::Error::invalid_length(
+                0,
+                &ExpectedChunkLength::<N>,
+            ));
+        }
+        Ok(len)
+    }
+}
+
+struct ExpectedChunkLength<const N: usize>;
+
+impl<const N: usize> serde::de::Expected for ExpectedChunkLength<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a chunk of {N} items, or between 1 and {N} items for the last chunk"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::Chunks::<2, crate::Str>")] Vec<i32>);
+
+    #[test]
+    fn chunks_roundtrip() {
+        check_serialization(
+            Foo(vec![1, 2, 3, 4, 5]),
+            json!([["1", "2"], ["3", "4"], ["5"]]),
+        );
+    }
+
+    #[test]
+    fn exact_chunks_roundtrip() {
+        check_serialization(Foo(vec![1, 2, 3, 4]), json!([["1", "2"], ["3", "4"]]));
+    }
+
+    #[test]
+    fn empty_chunks_roundtrip() {
+        check_serialization(Foo(Vec::new()), json!([]));
+    }
+
+    #[test]
+    fn short_chunk_before_last_fails() {
+        serde_json::from_value::<Foo>(json!([["1"], ["2", "3"]])).unwrap_err();
+    }
+
+    #[test]
+    fn long_chunk_fails() {
+        serde_json::from_value::<Foo>(json!([["1", "2", "3"]])).unwrap_err();
+    }
+
+    #[test]
+    fn empty_chunk_fails() {
+        serde_json::from_value::<Foo>(json!([[]])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Zero(#[serde(with = "crate::Chunks::<0, crate::Id>")] Vec<i32>);
+
+    #[test]
+    fn zero_chunk_size_fails() {
+        serde_json::to_value(Zero(vec![1])).unwrap_err();
+        serde_json::from_value::<Zero>(json!([])).unwrap_err();
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, RangeBound, SerializeWith};
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to clamp a value deserialized with `F` to the bounds `B`
+///
+/// Bounds are inclusive and provided by implementing [`RangeBound`]. Unlike
+/// [`InRange`](crate::InRange), out-of-bound values are not rejected but replaced with the closest
+/// bound, as with [`Ord::clamp`]. Serialization is forwarded to `F` unchanged. Deserialization
+/// fails if `B::MIN` is greater than `B::MAX`.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct Volume;
+///
+/// impl sa::RangeBound for Volume {
+///     type Value = u8;
+///     const MIN: u8 = 0;
+///     const MAX: u8 = 100;
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Clamp::<Volume>")] u8);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!(150)).unwrap();
+/// assert_eq!(deserialized, Foo(100));
+/// ```
+pub struct Clamp<B, F = crate::Id>(PhantomData<(B, F)>);
+
+impl<B, F> Clamp<B, F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<B, F, T> SerializeWith<T> for Clamp<B, F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, B, F, T> DeserializeWith<'de, T> for Clamp<B, F>
+where
+    B: RangeBound<Value = T>,
+    F: DeserializeWith<'de, T>,
+    T: Ord,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = F::deserialize_with(deserializer)?;
+        if B::MIN > B::MAX {
+            return Err(D::Error::custom(
+                "invalid clamp bounds: minimum exceeds maximum",
+            ));
+        }
+        Ok(value.clamp(B::MIN, B::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    struct Volume;
+
+    impl sa::RangeBound for Volume {
+        type Value = i32;
+        const MIN: i32 = 0;
+        const MAX: i32 = 100;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Clamp::<Volume, sa::Str>")] i32);
+
+    #[test]
+    fn value_within_bounds_is_kept() {
+        check_serialization(Foo(0), json!("0"));
+        check_serialization(Foo(42), json!("42"));
+        check_serialization(Foo(100), json!("100"));
+    }
+
+    #[test]
+    fn value_out_of_bounds_is_clamped() {
+        let deserialized = serde_json::from_value::<Foo>(json!("150")).unwrap();
+        assert_eq!(deserialized, Foo(100));
+        let deserialized = serde_json::from_value::<Foo>(json!("-5")).unwrap();
+        assert_eq!(deserialized, Foo(0));
+    }
+
+    #[test]
+    fn invalid_value_fails() {
+        serde_json::from_value::<Foo>(json!("x")).unwrap_err();
+    }
+
+    struct Reversed;
+
+    impl sa::RangeBound for Reversed {
+        type Value = i32;
+        const MIN: i32 = 10;
+        const MAX: i32 = 0;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Bad(#[serde(with = "sa::Clamp::<Reversed>")] i32);
+
+    #[test]
+    fn reversed_bounds_fail() {
+        serde_json::from_value::<Bad>(json!(5)).unwrap_err();
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Bytes, DeserializeWith, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::{fmt::Display, marker::PhantomData};
+use serde::{
+    de::Error as _,
+    ser::{Error as _, SerializeTuple},
+    Deserialize, Deserializer, Serializer,
+};
+
+/// Adapter to compress the serialized form of a value when it is larger than `N` bytes
+///
+/// This adapter is available with the `json` feature. The value is serialized to JSON with `F`
+/// using `serde_json`, and the resulting bytes are serialized as a tuple of a flag byte and a
+/// payload. If there are at most `N` bytes, the flag is 0 and the payload is the bytes as is.
+/// Otherwise, the flag is 1 and the payload is the bytes compressed with `A`. Any other flag results
+/// in an error when deserializing. The payload is then decompressed if needed and parsed as JSON
+/// with `F`.
+///
+/// The JSON bytes are buffered, and deserialization always parses from an owned buffer, so the
+/// value cannot borrow from the input.
+///
+/// The `Gzip` algorithm is available with the `gzip` feature.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "gzip")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::CompressOver::<16, sa::Gzip, sa::Seq<sa::Str>>")] Vec<u8>);
+///
+/// let foo = Foo(vec![1, 2]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([0, br#"["1","2"]"#]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+///
+/// let foo = Foo(vec![0; 1000]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized[0], json!(1));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct CompressOver<const N: usize, A, F>(PhantomData<(A, F)>);
+
+impl<const N: usize, A, F> CompressOver<N, A, F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Compression algorithm used by [`CompressOver`]
+pub trait Compression {
+    /// Error compressing or decompressing
+    type Error: Display;
+
+    /// Compresses bytes
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Self::Error>;
+
+    /// Decompresses bytes
+    ///
+    /// Implementations should limit the size of the decompressed data, as it comes from untrusted
+    /// input.
+    fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Self::Error>;
+}
+
+const UNCOMPRESSED: u8 = 0;
+const COMPRESSED: u8 = 1;
+
+impl<const N: usize, A, F, T> SerializeWith<T> for CompressOver<N, A, F>
+where
+    A: Compression,
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes =
+            serde_json::to_vec(&WithEncoding::<&F, &T>::from(value)).map_err(S::Error::custom)?;
+        let (flag, payload) = if bytes.len() <= N {
+            (UNCOMPRESSED, bytes)
+        } else {
+            (COMPRESSED, A::compress(&bytes).map_err(S::Error::custom)?)
+        };
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&flag)?;
+        tuple.serialize_element(&WithEncoding::<Bytes, _>::from(&payload))?;
+        tuple.end()
+    }
+}
+
+impl<'de, const N: usize, A, F, T> DeserializeWith<'de, T> for CompressOver<N, A, F>
+where
+    A: Compression,
+    F: for<'a> DeserializeWith<'a, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (flag, payload) = <(u8, WithEncoding<Bytes, Vec<u8>>)>::deserialize(deserializer)?;
+        let payload = payload.into_inner();
+        let bytes = match flag {
+            UNCOMPRESSED => payload,
+            COMPRESSED => A::decompress(&payload).map_err(D::Error::custom)?,
+            _ => {
+                return Err(D::Error::custom(format_args!(
+                    "invalid compression flag {flag}"
+                )))
+            }
+        };
+        serde_json::from_slice::<WithEncoding<F, T>>(&bytes)
+            .map(WithEncoding::into_inner)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Gzip compression
+///
+/// Decompression fails if the decompressed data is larger than `MAX` bytes, which defaults to
+/// 16 MiB. This prevents small malicious inputs from expanding to huge buffers.
+///
+/// This requires the `gzip` feature.
+#[cfg(feature = "gzip")]
+pub struct Gzip<const MAX: usize = { 16 * 1024 * 1024 }>;
+
+#[cfg(feature = "gzip")]
+impl<const MAX: usize> Compression for Gzip<MAX> {
+    type Error = std::io::Error;
+
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(bytes)?;
+        encoder.finish()
+    }
+
+    fn decompress(bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        use std::io::Read;
+
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(bytes)
+            .take(MAX.saturating_add(1) as u64)
+            .read_to_end(&mut decompressed)?;
+        if decompressed.len() > MAX {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("decompressed data exceeds {MAX} bytes"),
+            ));
+        }
+        Ok(decompressed)
+    }
+}
+
+#[cfg(all(feature = "gzip", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::CompressOver::<512, sa::Gzip, sa::Id>")] Vec<u8>);
+
+    #[test]
+    fn small_payload_is_not_compressed() {
+        check_serialization(Foo(vec![1, 2, 3]), json!([0, b"[1,2,3]"]));
+    }
+
+    #[test]
+    fn large_payload_is_compressed() {
+        let original = Foo(vec![7; 2048]);
+        let serialized = serde_json::to_value(&original).unwrap();
+        assert_eq!(serialized[0], json!(1));
+        assert!(serialized[1].as_array().unwrap().len() < 512);
+        let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn large_payload_roundtrips_with_bincode() {
+        let original = Foo((0..=255).cycle().take(4096).collect());
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::CompressOver::<16, sa::Gzip, sa::Seq<sa::Str>>")] Vec<u32>);
+
+    #[test]
+    fn output_of_inner_adapter_is_compressed() {
+        let original = Text(vec![12345; 100]);
+        let serialized = serde_json::to_value(&original).unwrap();
+        assert_eq!(serialized[0], json!(1));
+        let deserialized = serde_json::from_value::<Text>(serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn invalid_compression_flag_fails() {
+        serde_json::from_value::<Foo>(json!([2, b"[1,2,3]"])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Capped(#[serde(with = "sa::CompressOver::<512, sa::Gzip<1024>, sa::Id>")] Vec<u8>);
+
+    #[test]
+    fn decompressed_payload_over_limit_fails() {
+        let serialized = serde_json::to_value(Foo(vec![0; 2048])).unwrap();
+        assert!(serialized[1].as_array().unwrap().len() < 1024);
+        let e = serde_json::from_value::<Capped>(serialized).unwrap_err();
+        assert!(e.to_string().contains("exceeds 1024 bytes"));
+        let serialized = serde_json::to_value(Foo(vec![0; 300])).unwrap();
+        assert_eq!(serialized[0], json!(1));
+        serde_json::from_value::<Capped>(serialized).unwrap();
+    }
+}
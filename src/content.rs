@@ -0,0 +1,419 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        value::{MapDeserializer, SeqDeserializer},
+        DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, Unexpected,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+
+/// Buffered serde data model value
+///
+/// This allows deserializing the same input multiple times. Deserializing into `Content` requires
+/// a self-describing format.
+#[derive(Debug)]
+pub(crate) enum Content<'de> {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    String(String),
+    Str(&'de str),
+    ByteBuf(Vec<u8>),
+    Bytes(&'de [u8]),
+    None,
+    Some(Box<Content<'de>>),
+    Unit,
+    Newtype(Box<Content<'de>>),
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>),
+}
+
+impl Content<'_> {
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Content::Bool(b) => Unexpected::Bool(*b),
+            Content::U8(n) => Unexpected::Unsigned(u64::from(*n)),
+            Content::U16(n) => Unexpected::Unsigned(u64::from(*n)),
+            Content::U32(n) => Unexpected::Unsigned(u64::from(*n)),
+            Content::U64(n) => Unexpected::Unsigned(*n),
+            Content::U128(_) => Unexpected::Other("u128"),
+            Content::I8(n) => Unexpected::Signed(i64::from(*n)),
+            Content::I16(n) => Unexpected::Signed(i64::from(*n)),
+            Content::I32(n) => Unexpected::Signed(i64::from(*n)),
+            Content::I64(n) => Unexpected::Signed(*n),
+            Content::I128(_) => Unexpected::Other("i128"),
+            Content::F32(x) => Unexpected::Float(f64::from(*x)),
+            Content::F64(x) => Unexpected::Float(*x),
+            Content::Char(c) => Unexpected::Char(*c),
+            Content::String(s) => Unexpected::Str(s),
+            Content::Str(s) => Unexpected::Str(s),
+            Content::ByteBuf(b) => Unexpected::Bytes(b),
+            Content::Bytes(b) => Unexpected::Bytes(b),
+            Content::None | Content::Some(_) => Unexpected::Option,
+            Content::Unit => Unexpected::Unit,
+            Content::Newtype(_) => Unexpected::NewtypeStruct,
+            Content::Seq(_) => Unexpected::Seq,
+            Content::Map(_) => Unexpected::Map,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+macro_rules! visit_content {
+    ($($f:ident $t:ty => $variant:ident,)*) => {
+        $(
+            fn $f<E>(self, v: $t) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Content::$variant(v))
+            }
+        )*
+    };
+}
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    visit_content! {
+        visit_bool bool => Bool,
+        visit_u8 u8 => U8,
+        visit_u16 u16 => U16,
+        visit_u32 u32 => U32,
+        visit_u64 u64 => U64,
+        visit_u128 u128 => U128,
+        visit_i8 i8 => I8,
+        visit_i16 i16 => I16,
+        visit_i32 i32 => I32,
+        visit_i64 i64 => I64,
+        visit_i128 i128 => I128,
+        visit_f32 f32 => F32,
+        visit_f64 f64 => F64,
+        visit_char char => Char,
+        visit_string String => String,
+        visit_borrowed_str &'de str => Str,
+        visit_byte_buf Vec<u8> => ByteBuf,
+        visit_borrowed_bytes &'de [u8] => Bytes,
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Content::ByteBuf(v.to_owned()))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer).map(|c| Content::Some(Box::new(c)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Content::deserialize(deserializer).map(|c| Content::Newtype(Box::new(c)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Content::Map(entries))
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, access) = data.variant::<Content<'de>>()?;
+        let value = access.newtype_variant::<Content<'de>>()?;
+        Ok(Content::Map([(variant, value)].into()))
+    }
+}
+
+/// Deserializer reading from buffered [`Content`]
+///
+/// Whether the original format is human-readable is preserved.
+pub(crate) struct ContentRefDeserializer<'a, 'de, E> {
+    content: &'a Content<'de>,
+    human_readable: bool,
+    _e: PhantomData<E>,
+}
+
+impl<'a, 'de, E> ContentRefDeserializer<'a, 'de, E> {
+    pub(crate) fn new(content: &'a Content<'de>, human_readable: bool) -> Self {
+        Self {
+            content,
+            human_readable,
+            _e: PhantomData,
+        }
+    }
+
+    fn nested(&self, content: &'a Content<'de>) -> Self {
+        Self::new(content, self.human_readable)
+    }
+}
+
+impl<E> Clone for ContentRefDeserializer<'_, '_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for ContentRefDeserializer<'_, '_, E> {}
+
+impl<'a, 'de, E> Deserializer<'de> for ContentRefDeserializer<'a, 'de, E>
+where
+    E: serde::de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(*v),
+            Content::U8(v) => visitor.visit_u8(*v),
+            Content::U16(v) => visitor.visit_u16(*v),
+            Content::U32(v) => visitor.visit_u32(*v),
+            Content::U64(v) => visitor.visit_u64(*v),
+            Content::U128(v) => visitor.visit_u128(*v),
+            Content::I8(v) => visitor.visit_i8(*v),
+            Content::I16(v) => visitor.visit_i16(*v),
+            Content::I32(v) => visitor.visit_i32(*v),
+            Content::I64(v) => visitor.visit_i64(*v),
+            Content::I128(v) => visitor.visit_i128(*v),
+            Content::F32(v) => visitor.visit_f32(*v),
+            Content::F64(v) => visitor.visit_f64(*v),
+            Content::Char(v) => visitor.visit_char(*v),
+            Content::String(v) => visitor.visit_str(v),
+            Content::Str(v) => visitor.visit_borrowed_str(v),
+            Content::ByteBuf(v) => visitor.visit_bytes(v),
+            Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.nested(v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.nested(v)),
+            Content::Seq(items) => {
+                let mut seq = SeqDeserializer::new(items.iter().map(|x| self.nested(x)));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(entries) => {
+                let mut map = MapDeserializer::new(
+                    entries
+                        .iter()
+                        .map(|(k, v)| (self.nested(k), self.nested(v))),
+                );
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(self.nested(v)),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::Newtype(v) => visitor.visit_newtype_struct(self.nested(v)),
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.content {
+            Content::String(_) | Content::Str(_) => visitor.visit_enum(ContentEnumAccess {
+                variant: self,
+                value: None,
+            }),
+            Content::Map(entries) if entries.len() == 1 => {
+                let (variant, value) = &entries[0];
+                visitor.visit_enum(ContentEnumAccess {
+                    variant: self.nested(variant),
+                    value: Some(self.nested(value)),
+                })
+            }
+            other => Err(E::invalid_type(other.unexpected(), &"enum")),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'a, 'de, E> IntoDeserializer<'de, E> for ContentRefDeserializer<'a, 'de, E>
+where
+    E: serde::de::Error,
+{
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+struct ContentEnumAccess<'a, 'de, E> {
+    variant: ContentRefDeserializer<'a, 'de, E>,
+    value: Option<ContentRefDeserializer<'a, 'de, E>>,
+}
+
+impl<'a, 'de, E> EnumAccess<'de> for ContentEnumAccess<'a, 'de, E>
+where
+    E: serde::de::Error,
+{
+    type Error = E;
+    type Variant = ContentVariantAccess<'a, 'de, E>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(self.variant)?;
+        Ok((variant, ContentVariantAccess { value: self.value }))
+    }
+}
+
+struct ContentVariantAccess<'a, 'de, E> {
+    value: Option<ContentRefDeserializer<'a, 'de, E>>,
+}
+
+impl<'de, E> VariantAccess<'de> for ContentVariantAccess<'_, 'de, E>
+where
+    E: serde::de::Error,
+{
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None => Ok(()),
+            Some(value) => Deserialize::deserialize(value),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.value {
+            Some(value) => seed.deserialize(value),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"newtype variant")),
+        }
+    }
+
+    fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value) => value.deserialize_seq(visitor),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"tuple variant")),
+        }
+    }
+
+    fn struct_variant<V>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Some(value) => value.deserialize_map(visitor),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"struct variant")),
+        }
+    }
+}
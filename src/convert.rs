@@ -132,3 +132,49 @@ pub type TryConvert<T, F = crate::Id> = crate::Codec<crate::TryInto<T, F>, crate
 /// ```
 pub type RefTryConvert<T, F = crate::Id> =
     crate::Codec<crate::AddRef<crate::TryInto<T, F>>, crate::TryFrom<T, F>>;
+
+/// Adapter to serialize a value as a tuple, with an adapter for each element
+///
+/// The value is converted to the tuple type `T` using [`From`], and the tuple is serialized using
+/// `F`, which is typically a tuple of adapters with one adapter per element. When deserializing, a
+/// `T` is deserialized using `F` and converted to the desired type. Types that are conveniently
+/// destructured into a fixed set of fields can thus be serialized as a serde tuple.
+///
+/// This is [`Convert`] under a name stating the intent.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl From<Point> for (i32, i32) {
+///     fn from(Point { x, y }: Point) -> Self {
+///         (x, y)
+///     }
+/// }
+///
+/// impl From<(i32, i32)> for Point {
+///     fn from((x, y): (i32, i32)) -> Self {
+///         Point { x, y }
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Shape(
+///     #[serde(with = "sa::Seq::<sa::AsTuple<(i32, i32), (sa::Id, sa::Str)>>")] Vec<Point>,
+/// );
+///
+/// let shape = Shape(vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+/// let serialized = serde_json::to_value(&shape).unwrap();
+/// assert_eq!(serialized, json!([[1, "2"], [3, "4"]]));
+/// let deserialized = serde_json::from_value::<Shape>(serialized).unwrap();
+/// assert_eq!(deserialized, shape);
+/// ```
+pub type AsTuple<T, F = crate::Id> = Convert<T, F>;
@@ -0,0 +1,72 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::SerializeWith;
+use core::marker::PhantomData;
+use serde::Serializer;
+
+/// Adapter to serialize the number of items in a collection instead of the items
+///
+/// The number of items is a [`usize`] serialized with `F`. This adapter can only be used for
+/// serialization, as the items cannot be recovered from their count.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::Serialize;
+/// use serde_json::json;
+///
+/// #[derive(Serialize)]
+/// struct Foo(#[serde(with = "sa::Count::<sa::Id>")] Vec<i32>);
+///
+/// let v = serde_json::to_value(Foo(vec![3, 8, 5])).unwrap();
+/// assert_eq!(v, json!(3));
+/// ```
+pub struct Count<F = crate::Id>(PhantomData<F>);
+
+impl<F> Count<F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+}
+
+impl<F, C> SerializeWith<C> for Count<F>
+where
+    F: SerializeWith<usize>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&container.into_iter().count(), serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as sa;
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct Foo<const N: usize>(#[serde(with = "sa::Count::<sa::Str>")] [u8; N]);
+
+    #[test]
+    fn count_serializes_number_of_items() {
+        assert_eq!(serde_json::to_value(Foo([1, 2, 3])).unwrap(), json!("3"));
+        assert_eq!(serde_json::to_value(Foo([])).unwrap(), json!("0"));
+    }
+
+    #[derive(Serialize)]
+    struct Bar(#[serde(with = "sa::Count::<sa::Id>")] Option<u8>);
+
+    #[test]
+    fn count_supports_any_iterable() {
+        assert_eq!(serde_json::to_value(Bar(Some(3))).unwrap(), json!(1));
+        assert_eq!(serde_json::to_value(Bar(None)).unwrap(), json!(0));
+    }
+}
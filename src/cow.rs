@@ -1,14 +1,17 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
-use crate::{DeserializeWith, SerializeWith};
-use alloc::borrow::ToOwned;
-use core::marker::PhantomData;
-use serde::{Deserializer, Serializer};
+use crate::{Bytes, DeserializeWith, SerializeWith};
+use alloc::{borrow::ToOwned, string::String};
+use core::{fmt, marker::PhantomData};
+use serde::{de::Visitor, Deserializer, Serializer};
 
 /// [`Cow`](alloc::borrow::Cow) adapter
 ///
 /// This adapter allows to customize how a type inside [`Cow`](alloc::borrow::Cow) is serialized.
 ///
+/// Deserialization always produces [`Cow::Owned`](alloc::borrow::Cow::Owned). See [`BorrowCow`]
+/// to borrow strings and bytes from the input.
+///
 /// # Example
 /// ```
 /// use serde::{Deserialize, Serialize};
@@ -70,6 +73,121 @@ where
     }
 }
 
+/// Adapter for `Cow<str>` and `Cow<[u8]>` borrowing from the input when possible
+///
+/// When deserializing, [`Cow::Borrowed`](alloc::borrow::Cow::Borrowed) is returned if the
+/// format lends data from the input, and [`Cow::Owned`](alloc::borrow::Cow::Owned) otherwise,
+/// e.g. when a JSON string contains escape sequences. The field must be annotated with
+/// `#[serde(borrow)]` for the data to be borrowed.
+///
+/// Strings are serialized as strings and bytes are serialized with [`Bytes`].
+///
+/// This is needed because [`Cow<F>`](Cow) deserializes the owned type with `F` and therefore
+/// always produces owned data, and the `serde` implementation for `Cow` does the same.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use std::borrow::Cow;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo<'a>(#[serde(borrow, with = "sa::BorrowCow")] Cow<'a, str>);
+///
+/// let foo = serde_json::from_str::<Foo<'_>>(r#""abc""#).unwrap();
+/// assert!(matches!(foo.0, Cow::Borrowed("abc")));
+/// let foo = serde_json::from_str::<Foo<'_>>(r#""a\nc""#).unwrap();
+/// assert!(matches!(foo.0, Cow::Owned(_)));
+/// ```
+pub struct BorrowCow;
+
+impl BorrowCow {
+    crate::adapter_methods!(pub);
+}
+
+impl SerializeWith<alloc::borrow::Cow<'_, str>> for BorrowCow {
+    fn serialize_with<S: Serializer>(
+        value: &alloc::borrow::Cow<'_, str>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value)
+    }
+}
+
+impl SerializeWith<alloc::borrow::Cow<'_, [u8]>> for BorrowCow {
+    fn serialize_with<S: Serializer>(
+        value: &alloc::borrow::Cow<'_, [u8]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Bytes::serialize_with(value, serializer)
+    }
+}
+
+impl<'de: 'a, 'a> DeserializeWith<'de, alloc::borrow::Cow<'a, str>> for BorrowCow {
+    fn deserialize_with<D>(deserializer: D) -> Result<alloc::borrow::Cow<'a, str>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(CowStrVisitor(PhantomData))
+    }
+}
+
+impl<'de: 'a, 'a> DeserializeWith<'de, alloc::borrow::Cow<'a, [u8]>> for BorrowCow {
+    fn deserialize_with<D>(deserializer: D) -> Result<alloc::borrow::Cow<'a, [u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Bytes::deserialize_with(deserializer)
+    }
+}
+
+struct CowStrVisitor<'a>(PhantomData<&'a ()>);
+
+impl<'de: 'a, 'a> Visitor<'de> for CowStrVisitor<'a> {
+    type Value = alloc::borrow::Cow<'a, str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(alloc::borrow::Cow::Borrowed(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(alloc::borrow::Cow::Owned(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(alloc::borrow::Cow::Owned(v))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        core::str::from_utf8(v)
+            .map(alloc::borrow::Cow::Borrowed)
+            .map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::check_serialization;
@@ -84,4 +202,43 @@ mod tests {
     fn cow_adapter_roundtrips() {
         check_serialization(Foo(Cow::Owned(33)), json!("33"));
     }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text<'a>(#[serde(borrow, with = "crate::BorrowCow")] Cow<'a, str>);
+
+    #[test]
+    fn cow_str_is_borrowed() {
+        let original = Text(Cow::Borrowed("foobar"));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Text<'_>>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+        let Text(Cow::Borrowed(_)) = deserialized else {
+            panic!("Expected Cow::Borrowed");
+        };
+    }
+
+    #[test]
+    fn cow_str_is_owned_if_input_cannot_be_borrowed() {
+        let serialized = serde_json::to_value(Text(Cow::Owned("a\nb".into()))).unwrap();
+        assert_eq!(serialized, json!("a\nb"));
+        let deserialized = serde_json::from_str::<Text<'_>>(r#""a\nb""#).unwrap();
+        let Text(Cow::Owned(s)) = deserialized else {
+            panic!("Expected Cow::Owned");
+        };
+        assert_eq!(s, "a\nb");
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Blob<'a>(#[serde(borrow, with = "crate::BorrowCow")] Cow<'a, [u8]>);
+
+    #[test]
+    fn cow_bytes_are_borrowed() {
+        let original = Blob(Cow::Borrowed(b"foobar"));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Blob<'_>>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+        let Blob(Cow::Borrowed(_)) = deserialized else {
+            panic!("Expected Cow::Borrowed");
+        };
+    }
 }
@@ -0,0 +1,125 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{
+    content::{Content, ContentRefDeserializer},
+    DeserializeWith, SerializeWith,
+};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Adapter to deserialize the default value when deserialization with `F` fails
+///
+/// The input is buffered before deserializing it with `F`, so that a failure does not leave the
+/// deserializer in an inconsistent state. Buffering requires a self-describing format, and errors
+/// from the format itself (e.g. malformed input) are still reported.
+///
+/// Serialization uses `F`.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::DefaultOnError::<sa::Str>")] u32);
+///
+/// let foo = serde_json::from_value::<Foo>(json!("33")).unwrap();
+/// assert_eq!(foo, Foo(33));
+/// let foo = serde_json::from_value::<Foo>(json!("garbage")).unwrap();
+/// assert_eq!(foo, Foo(0));
+/// ```
+pub struct DefaultOnError<F = crate::Id>(PhantomData<F>);
+
+impl<F> DefaultOnError<F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<F, T> SerializeWith<T> for DefaultOnError<F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, T> for DefaultOnError<F>
+where
+    F: DeserializeWith<'de, T>,
+    T: Default,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+        let content = Content::deserialize(deserializer)?;
+        Ok(F::deserialize_with(ContentRefDeserializer::<D::Error>::new(
+            &content,
+            human_readable,
+        ))
+        .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::DefaultOnError::<sa::Str>")] u32);
+
+    #[test]
+    fn valid_value_roundtrips() {
+        check_serialization(Foo(33), json!("33"));
+    }
+
+    #[test]
+    fn invalid_value_deserializes_as_default() {
+        let deserialized = serde_json::from_value::<Foo>(json!("garbage")).unwrap();
+        assert_eq!(deserialized, Foo(0));
+        let deserialized = serde_json::from_value::<Foo>(json!([1, 2])).unwrap();
+        assert_eq!(deserialized, Foo(0));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Bar {
+        #[serde(with = "sa::DefaultOnError::<sa::Seq<sa::Str>>")]
+        a: Vec<u32>,
+        b: u32,
+    }
+
+    #[test]
+    fn following_fields_are_deserialized_after_failure() {
+        let deserialized =
+            serde_json::from_str::<Bar>(r#"{ "a": ["1", {"x": 2}, "3"], "b": 4 }"#).unwrap();
+        assert_eq!(deserialized, Bar { a: vec![], b: 4 });
+    }
+
+    #[test]
+    fn malformed_input_fails() {
+        serde_json::from_str::<Bar>(r#"{ "a": ["1", "b": 4 }"#).unwrap_err();
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Adapter to deserialize null as the default value
+///
+/// When deserializing, a missing value (e.g. `null` in JSON) results in `T::default()`, and a
+/// present value is deserialized with `F`. When serializing, the value is serialized with `F`
+/// without being wrapped in an option. As a result, roundtrips require a self-describing format.
+///
+/// This adapter only applies to a field that is present in the input. For a missing field to also
+/// result in the default value, `#[serde(default)]` needs to be added to the field.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(default, with = "sa::DefaultOnNull::<sa::Str>")]
+///     count: u32,
+/// }
+///
+/// let foo = serde_json::from_value::<Foo>(json!({ "count": null })).unwrap();
+/// assert_eq!(foo, Foo { count: 0 });
+/// let foo = serde_json::from_value::<Foo>(json!({})).unwrap();
+/// assert_eq!(foo, Foo { count: 0 });
+/// let foo = serde_json::from_value::<Foo>(json!({ "count": "33" })).unwrap();
+/// assert_eq!(foo, Foo { count: 33 });
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "count": "33" }));
+/// ```
+pub struct DefaultOnNull<F = crate::Id>(PhantomData<F>);
+
+impl<F> DefaultOnNull<F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<F, T> SerializeWith<T> for DefaultOnNull<F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, T> for DefaultOnNull<F>
+where
+    F: DeserializeWith<'de, T>,
+    T: Default,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let x: Option<WithEncoding<F, T>> = Deserialize::deserialize(deserializer)?;
+        Ok(x.map(WithEncoding::into_inner).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::DefaultOnNull::<sa::Str>")] u32);
+
+    #[test]
+    fn value_roundtrips() {
+        check_serialization(Foo(33), json!("33"));
+        check_serialization(Foo(0), json!("0"));
+    }
+
+    #[test]
+    fn null_deserializes_as_default() {
+        let deserialized = serde_json::from_value::<Foo>(json!(null)).unwrap();
+        assert_eq!(deserialized, Foo(0));
+    }
+
+    #[test]
+    fn invalid_value_fails() {
+        serde_json::from_value::<Foo>(json!(33)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Bar {
+        #[serde(with = "sa::DefaultOnNull::<sa::Id>")]
+        a: u32,
+    }
+
+    #[test]
+    fn missing_field_without_serde_default_fails() {
+        serde_json::from_value::<Bar>(json!({})).unwrap_err();
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{text::TextSerializer, DeserializeWith, SerializeWith};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, StrDeserializer},
+        Visitor,
+    },
+    ser::Error as _,
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize a collection as a string of items separated by `SEP`
+///
+/// Each item is serialized with `F` as text, i.e. as a string or as a primitive formatted with its
+/// [`Display`](core::fmt::Display) implementation, and items are joined with `SEP`. When
+/// deserializing, the string is split at each `SEP` and each segment is deserialized with `F` from
+/// a string.
+///
+/// An empty collection is serialized as an empty string, and an empty string is deserialized as an
+/// empty collection. Otherwise, empty segments are not skipped and are deserialized like any other
+/// segment. As a consequence, a collection with a single item serialized as an empty string does
+/// not roundtrip. Serializing an item whose text contains `SEP` results in an error, as it could
+/// not be deserialized back.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Delimited::<',', sa::Str>")] Vec<i32>);
+///
+/// let foo = Foo(vec![1, 2, 3]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("1,2,3"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct Delimited<const SEP: char, F = crate::Id>(PhantomData<F>);
+
+impl<const SEP: char, F> Delimited<SEP, F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<const SEP: char, F, C, T> SerializeWith<C> for Delimited<SEP, F>
+where
+    F: SerializeWith<T>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut out = String::new();
+        for (i, item) in container.into_iter().enumerate() {
+            let item = F::serialize_with(item, TextSerializer).map_err(S::Error::custom)?;
+            if item.contains(SEP) {
+                return Err(S::Error::custom(format_args!(
+                    "item {item:?} contains separator {SEP:?}"
+                )));
+            }
+            if i != 0 {
+                out.push(SEP);
+            }
+            out.push_str(&item);
+        }
+        serializer.serialize_str(&out)
+    }
+}
+
+impl<'de, const SEP: char, F, C> DeserializeWith<'de, C> for Delimited<SEP, F>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DelimitedVisitor::<SEP, F, C>::new())
+    }
+}
+
+struct DelimitedVisitor<const SEP: char, F, C> {
+    _f: PhantomData<F>,
+    _c: PhantomData<fn() -> C>,
+}
+
+impl<const SEP: char, F, C> DelimitedVisitor<SEP, F, C> {
+    fn new() -> Self {
+        Self {
+            _f: PhantomData,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<'de, const SEP: char, F, C> Visitor<'de> for DelimitedVisitor<SEP, F, C>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string of items separated by {SEP:?}")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        segments::<SEP>(v)
+            .map(|s| F::deserialize_with(StrDeserializer::new(s)))
+            .collect()
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        segments::<SEP>(v)
+            .map(|s| F::deserialize_with(BorrowedStrDeserializer::new(s)))
+            .collect()
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+fn segments<const SEP: char>(s: &str) -> impl Iterator<Item = &str> {
+    Some(s)
+        .filter(|s| !s.is_empty())
+        .into_iter()
+        .flat_map(|s| s.split(SEP))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{string::String, vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Numbers(#[serde(with = "sa::Delimited::<',', sa::Str>")] Vec<i32>);
+
+    #[test]
+    fn items_are_joined() {
+        check_serialization(Numbers(vec![1, -2, 3]), json!("1,-2,3"));
+        check_serialization(Numbers(vec![1]), json!("1"));
+    }
+
+    #[test]
+    fn empty_collection_roundtrips() {
+        check_serialization(Numbers(Vec::new()), json!(""));
+    }
+
+    #[test]
+    fn empty_segment_is_not_skipped() {
+        serde_json::from_value::<Numbers>(json!("1,,2")).unwrap_err();
+        serde_json::from_value::<Numbers>(json!("1,")).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_item_fails() {
+        serde_json::from_value::<Numbers>(json!("1,x")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Words(#[serde(with = "sa::Delimited::<' '>")] Vec<String>);
+
+    #[test]
+    fn strings_roundtrip() {
+        check_serialization(
+            Words(vec!["foo".into(), "".into(), "bar".into()]),
+            json!("foo  bar"),
+        );
+    }
+
+    #[test]
+    fn item_containing_separator_fails_to_serialize() {
+        serde_json::to_value(Words(vec!["foo bar".into()])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Borrowed<'a>(#[serde(borrow, with = "sa::Delimited::<'|'>")] Vec<&'a str>);
+
+    #[test]
+    fn segments_can_be_borrowed() {
+        let deserialized = serde_json::from_str::<Borrowed<'_>>(r#""a|b""#).unwrap();
+        assert_eq!(deserialized, Borrowed(vec!["a", "b"]));
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt::Display, marker::PhantomData};
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize C-style enums as their integer discriminant
+///
+/// The enum is converted to an integer of type `T` with [`From`], and the integer is serialized
+/// with `F`. When deserializing, an integer is deserialized with `F` and converted to the enum
+/// with [`TryFrom`]. An integer matching no variant results in an error mentioning both the
+/// integer and the conversion error.
+///
+/// This is similar to [`TryConvert`](crate::TryConvert), with an error message dedicated to this
+/// use case.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(i32)]
+/// enum Color {
+///     Red = 1,
+///     Green = 2,
+///     Blue = 4,
+/// }
+///
+/// impl From<Color> for i32 {
+///     fn from(color: Color) -> i32 {
+///         color as i32
+///     }
+/// }
+///
+/// impl TryFrom<i32> for Color {
+///     type Error = &'static str;
+///
+///     fn try_from(n: i32) -> Result<Self, Self::Error> {
+///         [Color::Red, Color::Green, Color::Blue]
+///             .into_iter()
+///             .find(|&color| color as i32 == n)
+///             .ok_or("no such color")
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Discriminant::<i32>")] Color);
+///
+/// let foo = Foo(Color::Blue);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(4));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!(3)).is_err());
+/// ```
+pub struct Discriminant<T = i32, F = crate::Id> {
+    _convert: PhantomData<fn(T) -> T>,
+    _f: PhantomData<F>,
+}
+
+impl<R, F> Discriminant<R, F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<T, U, F> SerializeWith<U> for Discriminant<T, F>
+where
+    T: From<U>,
+    U: Clone,
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&value.clone().into(), serializer)
+    }
+}
+
+impl<'de, T, U, F> DeserializeWith<'de, U> for Discriminant<T, F>
+where
+    T: Copy + Display,
+    U: TryFrom<T>,
+    U::Error: Display,
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<U, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let n = F::deserialize_with(deserializer)?;
+        U::try_from(n).map_err(|e| D::Error::custom(format_args!("unknown discriminant {n}: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(u8)]
+    enum Level {
+        Low = 10,
+        High = 20,
+    }
+
+    impl From<Level> for u8 {
+        fn from(level: Level) -> u8 {
+            level as u8
+        }
+    }
+
+    impl TryFrom<u8> for Level {
+        type Error = &'static str;
+
+        fn try_from(n: u8) -> Result<Self, Self::Error> {
+            match n {
+                10 => Ok(Level::Low),
+                20 => Ok(Level::High),
+                _ => Err("no such level"),
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Discriminant::<u8, sa::Str>")] Level);
+
+    #[test]
+    fn enum_roundtrips_as_discriminant() {
+        check_serialization(Foo(Level::Low), json!("10"));
+        check_serialization(Foo(Level::High), json!("20"));
+    }
+
+    #[test]
+    fn unknown_discriminant_is_rejected() {
+        let e = serde_json::from_value::<Foo>(json!("15")).unwrap_err();
+        assert_eq!(e.to_string(), "unknown discriminant 15: no such level");
+    }
+}
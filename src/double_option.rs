@@ -0,0 +1,111 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Adapter for `Option<Option<T>>` distinguishing a missing field from an explicit null
+///
+/// `None` stands for a missing field, `Some(None)` for an explicit null, and `Some(Some(value))`
+/// for a value serialized with `F`. This is typically needed to represent partial updates, as in
+/// JSON merge patches.
+///
+/// The field must be annotated with `#[serde(default, skip_serializing_if = "Option::is_none")]`.
+/// Without `default`, deserializing a missing field fails. Without `skip_serializing_if`, `None` is
+/// serialized as null and is deserialized back as `Some(None)`.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Patch {
+///     #[serde(
+///         default,
+///         skip_serializing_if = "Option::is_none",
+///         with = "sa::DoubleOption::<sa::Str>"
+///     )]
+///     age: Option<Option<u32>>,
+/// }
+///
+/// let cases = [
+///     (Patch { age: None }, json!({})),
+///     (Patch { age: Some(None) }, json!({ "age": null })),
+///     (Patch { age: Some(Some(33)) }, json!({ "age": "33" })),
+/// ];
+///
+/// for (patch, expected) in cases {
+///     let serialized = serde_json::to_value(&patch).unwrap();
+///     assert_eq!(serialized, expected);
+///     let deserialized = serde_json::from_value::<Patch>(serialized).unwrap();
+///     assert_eq!(deserialized, patch);
+/// }
+/// ```
+pub struct DoubleOption<F = crate::Id>(PhantomData<F>);
+
+impl<F> DoubleOption<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<Option<Option<T>>> for DoubleOption<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &Option<Option<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = value
+            .as_ref()
+            .and_then(|x| x.as_ref().map(WithEncoding::<&F, &T>::from));
+        Serialize::serialize(&value, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, Option<Option<T>>> for DoubleOption<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let x: Option<WithEncoding<F, T>> = Deserialize::deserialize(deserializer)?;
+        Ok(Some(x.map(WithEncoding::into_inner)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Patch {
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "sa::DoubleOption::<sa::Str>"
+        )]
+        n: Option<Option<i32>>,
+    }
+
+    #[test]
+    fn missing_field_is_none() {
+        check_serialization(Patch { n: None }, json!({}));
+    }
+
+    #[test]
+    fn null_is_some_none() {
+        check_serialization(Patch { n: Some(None) }, json!({ "n": null }));
+    }
+
+    #[test]
+    fn value_goes_through_inner_adapter() {
+        check_serialization(Patch { n: Some(Some(-4)) }, json!({ "n": "-4" }));
+        serde_json::from_value::<Patch>(json!({ "n": -4 })).unwrap_err();
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Bytes, DeserializeWith, SerializeWith};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use serde::de::Error as _;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize integers as their little-endian bytes
+///
+/// An integer is serialized as a byte array of its size with [`Bytes`], and deserialized back
+/// from a byte array of the same size. All primitive integer types are supported, except `usize`
+/// and `isize` whose size depends on the platform.
+///
+/// A `Vec` of integers is serialized as a single byte sequence made of the concatenated bytes of
+/// each integer. [`Seq<LeBytes>`](crate::Seq) can be used instead to serialize each integer as a
+/// separate byte array.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::LeBytes")] Vec<u16>);
+///
+/// let foo = Foo(vec![1, 0x203]);
+/// let serialized = bincode::serialize(&foo).unwrap();
+/// assert_eq!(serialized, [4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 2]);
+/// let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct LeBytes;
+
+/// Adapter to serialize integers as their big-endian bytes
+///
+/// An integer is serialized as a byte array of its size with [`Bytes`], and deserialized back
+/// from a byte array of the same size. All primitive integer types are supported, except `usize`
+/// and `isize` whose size depends on the platform.
+///
+/// A `Vec` of integers is serialized as a single byte sequence made of the concatenated bytes of
+/// each integer. [`Seq<BeBytes>`](crate::Seq) can be used instead to serialize each integer as a
+/// separate byte array.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::BeBytes")] u32);
+///
+/// let foo = Foo(0x1020304);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([1, 2, 3, 4]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct BeBytes;
+
+macro_rules! impl_endian {
+    ($adapter:ident, $to_bytes:ident, $from_bytes:ident) => {
+        impl $adapter {
+            crate::adapter_methods!(pub);
+        }
+
+        impl_endian!(
+            $adapter, $to_bytes, $from_bytes;
+            u8, u16, u32, u64, u128, i8, i16, i32, i64, i128
+        );
+    };
+    ($adapter:ident, $to_bytes:ident, $from_bytes:ident; $($ty:ty),*) => {
+        $(
+            impl SerializeWith<$ty> for $adapter {
+                fn serialize_with<S: Serializer>(
+                    value: &$ty,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    Bytes::serialize_with(&value.$to_bytes(), serializer)
+                }
+            }
+
+            impl<'de> DeserializeWith<'de, $ty> for $adapter {
+                fn deserialize_with<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Bytes::deserialize_with(deserializer).map(<$ty>::$from_bytes)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl SerializeWith<Vec<$ty>> for $adapter {
+                fn serialize_with<S: Serializer>(
+                    value: &Vec<$ty>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let bytes = value.iter().flat_map(|x| x.$to_bytes()).collect::<Vec<_>>();
+                    Bytes::serialize_with(&bytes, serializer)
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            impl<'de> DeserializeWith<'de, Vec<$ty>> for $adapter {
+                fn deserialize_with<D>(deserializer: D) -> Result<Vec<$ty>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    const SIZE: usize = size_of::<$ty>();
+                    let bytes: Vec<u8> = Bytes::deserialize_with(deserializer)?;
+                    let chunks = bytes.chunks_exact(SIZE);
+                    if !chunks.remainder().is_empty() {
+                        return Err(D::Error::invalid_length(
+                            bytes.len(),
+                            &concat!("a multiple of the size of ", stringify!($ty)),
+                        ));
+                    }
+                    Ok(chunks
+                        .map(|chunk| <$ty>::$from_bytes(core::array::from_fn(|i| chunk[i])))
+                        .collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_endian!(LeBytes, to_le_bytes, from_le_bytes);
+impl_endian!(BeBytes, to_be_bytes, from_be_bytes);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Le(#[serde(with = "sa::LeBytes")] i32);
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Be(#[serde(with = "sa::BeBytes")] i32);
+
+    #[test]
+    fn integer_roundtrips_as_bytes() {
+        check_serialization(Le(-2), json!([0xfe, 0xff, 0xff, 0xff]));
+        check_serialization(Be(-2), json!([0xff, 0xff, 0xff, 0xfe]));
+    }
+
+    #[test]
+    fn integer_roundtrips_with_bincode() {
+        let serialized = bincode::serialize(&Be(0x1020304)).unwrap();
+        assert_eq!(serialized, [4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
+        assert_eq!(
+            bincode::deserialize::<Be>(&serialized).unwrap(),
+            Be(0x1020304)
+        );
+    }
+
+    #[test]
+    fn wrong_byte_count_fails() {
+        serde_json::from_value::<Le>(json!([1, 2, 3])).unwrap_err();
+        serde_json::from_value::<Le>(json!([1, 2, 3, 4, 5])).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Flat(#[serde(with = "sa::LeBytes")] alloc::vec::Vec<u16>);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_roundtrips_as_flat_bytes() {
+        check_serialization(Flat(alloc::vec![1, 0x203]), json!([1, 0, 3, 2]));
+        check_serialization(Flat(alloc::vec![]), json!([]));
+        serde_json::from_value::<Flat>(json!([1, 0, 3])).unwrap_err();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Items(#[serde(with = "sa::Seq::<sa::BeBytes>")] alloc::vec::Vec<u16>);
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn seq_serializes_each_integer_as_bytes() {
+        check_serialization(Items(alloc::vec![1, 0x203]), json!([[0, 1], [2, 3]]));
+    }
+}
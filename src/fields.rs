@@ -0,0 +1,148 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes `a` and `b` as struct `name` with fields named `fields`
+pub(crate) fn serialize_pair<S, A, B>(
+    serializer: S,
+    name: &'static str,
+    fields: &'static [&'static str; 2],
+    a: &A,
+    b: &B,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: Serialize + ?Sized,
+    B: Serialize + ?Sized,
+{
+    let mut out = serializer.serialize_struct(name, 2)?;
+    out.serialize_field(fields[0], a)?;
+    out.serialize_field(fields[1], b)?;
+    out.end()
+}
+
+/// Deserializes struct `name` with fields named `fields` into a pair
+pub(crate) fn deserialize_pair<'de, D, A, B>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str; 2],
+) -> Result<(A, B), D::Error>
+where
+    D: Deserializer<'de>,
+    A: Deserialize<'de>,
+    B: Deserialize<'de>,
+{
+    deserializer.deserialize_struct(
+        name,
+        fields,
+        PairVisitor {
+            name,
+            fields,
+            _p: PhantomData,
+        },
+    )
+}
+
+struct PairVisitor<A, B> {
+    name: &'static str,
+    fields: &'static [&'static str; 2],
+    _p: PhantomData<fn() -> (A, B)>,
+}
+
+impl<'de, A, B> Visitor<'de> for PairVisitor<A, B>
+where
+    A: Deserialize<'de>,
+    B: Deserialize<'de>,
+{
+    type Value = (A, B);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "struct {}", self.name)
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let a = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+        let b = seq
+            .next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+        Ok((a, b))
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut a = None;
+        let mut b = None;
+        while let Some(field) = map.next_key_seed(FieldIndex(self.fields))? {
+            match field {
+                Some(0) if a.is_some() => {
+                    return Err(serde::de::Error::duplicate_field(self.fields[0]))
+                }
+                Some(0) => a = Some(map.next_value()?),
+                Some(_) if b.is_some() => {
+                    return Err(serde::de::Error::duplicate_field(self.fields[1]))
+                }
+                Some(_) => b = Some(map.next_value()?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let a = a.ok_or_else(|| serde::de::Error::missing_field(self.fields[0]))?;
+        let b = b.ok_or_else(|| serde::de::Error::missing_field(self.fields[1]))?;
+        Ok((a, b))
+    }
+}
+
+struct FieldIndex(&'static [&'static str; 2]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIndex {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field identifier")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(usize::try_from(v).ok().filter(|&i| i < self.0.len()))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(self.0.iter().position(|&name| name == v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(self.0.iter().position(|name| name.as_bytes() == v))
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to deserialize booleans from common truthy and falsy tokens
+///
+/// See [`FlexBoolWith`] for details.
+pub type FlexBool = FlexBoolWith<CommonBoolTokens>;
+
+/// Adapter for [`bool`] accepting strings and numbers when deserializing
+///
+/// Booleans are serialized as [`bool`]. When deserializing, the following are accepted:
+/// - Booleans.
+/// - Strings listed in the [`BoolTokens`] implementation of `B`, compared ignoring ASCII case.
+/// - Integers listed in the [`BoolTokens`] implementation of `B`, 0 for `false` and 1 for `true`
+///   by default.
+///
+/// Any other string or number results in an error. Deserialization relies on
+/// [`Deserializer::deserialize_any`], so it requires a self-describing format.
+///
+/// The [`FlexBool`] alias accepts the tokens of [`CommonBoolTokens`].
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::FlexBool")] bool);
+///
+/// let serialized = serde_json::to_value(Foo(true)).unwrap();
+/// assert_eq!(serialized, json!(true));
+/// for v in [json!(true), json!("Yes"), json!("on"), json!("1"), json!(1)] {
+///     assert_eq!(serde_json::from_value::<Foo>(v).unwrap(), Foo(true));
+/// }
+/// for v in [json!(false), json!("no"), json!("OFF"), json!("0"), json!(0)] {
+///     assert_eq!(serde_json::from_value::<Foo>(v).unwrap(), Foo(false));
+/// }
+/// assert!(serde_json::from_value::<Foo>(json!("maybe")).is_err());
+/// ```
+pub struct FlexBoolWith<B>(PhantomData<B>);
+
+impl<B> FlexBoolWith<B> {
+    crate::adapter_methods!(pub);
+}
+
+/// Strings accepted by [`FlexBoolWith`] when deserializing
+pub trait BoolTokens {
+    /// Strings deserialized as `true`, compared ignoring ASCII case
+    const TRUE: &'static [&'static str];
+    /// Strings deserialized as `false`, compared ignoring ASCII case
+    const FALSE: &'static [&'static str];
+    /// Integers deserialized as `true`
+    const TRUE_NUMBERS: &'static [i64] = &[1];
+    /// Integers deserialized as `false`
+    const FALSE_NUMBERS: &'static [i64] = &[0];
+}
+
+/// Common boolean tokens
+///
+/// `true`, `t`, `yes`, `y`, `on` and `1` are accepted as `true`, and `false`, `f`, `no`, `n`,
+/// `off` and `0` as `false`. The integers 1 and 0 are accepted as `true` and `false` respectively.
+pub struct CommonBoolTokens;
+
+impl BoolTokens for CommonBoolTokens {
+    const TRUE: &'static [&'static str] = &["true", "t", "yes", "y", "on", "1"];
+    const FALSE: &'static [&'static str] = &["false", "f", "no", "n", "off", "0"];
+}
+
+impl<B> SerializeWith<bool> for FlexBoolWith<B> {
+    fn serialize_with<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(*value)
+    }
+}
+
+impl<'de, B> DeserializeWith<'de, bool> for FlexBoolWith<B>
+where
+    B: BoolTokens,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FlexBoolVisitor::<B>::new())
+    }
+}
+
+struct FlexBoolVisitor<B> {
+    _tokens: PhantomData<B>,
+}
+
+impl<B> FlexBoolVisitor<B>
+where
+    B: BoolTokens,
+{
+    fn new() -> Self {
+        Self {
+            _tokens: PhantomData,
+        }
+    }
+
+    fn convert<E, N>(n: N) -> Result<bool, E>
+    where
+        E: serde::de::Error,
+        N: Copy + TryInto<i64> + fmt::Display,
+    {
+        match n.try_into() {
+            Ok(k) if B::TRUE_NUMBERS.contains(&k) => Ok(true),
+            Ok(k) if B::FALSE_NUMBERS.contains(&k) => Ok(false),
+            _ => Err(E::custom(format_args!("unknown boolean number {n}"))),
+        }
+    }
+}
+
+impl<'de, B> Visitor<'de> for FlexBoolVisitor<B>
+where
+    B: BoolTokens,
+{
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a boolean or a boolean token")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::convert(v)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let matches = |tokens: &[&str]| tokens.iter().any(|t| t.eq_ignore_ascii_case(v));
+        if matches(B::TRUE) {
+            Ok(true)
+        } else if matches(B::FALSE) {
+            Ok(false)
+        } else {
+            Err(E::custom(format_args!("unknown boolean token {v:?}")))
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::FlexBool")] bool);
+
+    #[test]
+    fn bool_roundtrips() {
+        check_serialization(Foo(true), json!(true));
+        check_serialization(Foo(false), json!(false));
+    }
+
+    #[test]
+    fn tokens_are_accepted_ignoring_case() {
+        for v in ["true", "T", "Yes", "y", "ON", "1"] {
+            assert_eq!(serde_json::from_value::<Foo>(json!(v)).unwrap(), Foo(true));
+        }
+        for v in ["FALSE", "f", "no", "N", "Off", "0"] {
+            assert_eq!(serde_json::from_value::<Foo>(json!(v)).unwrap(), Foo(false));
+        }
+    }
+
+    #[test]
+    fn zero_and_one_are_accepted() {
+        assert_eq!(serde_json::from_value::<Foo>(json!(1)).unwrap(), Foo(true));
+        assert_eq!(serde_json::from_value::<Foo>(json!(0)).unwrap(), Foo(false));
+    }
+
+    #[test]
+    fn unknown_tokens_fail() {
+        serde_json::from_value::<Foo>(json!("maybe")).unwrap_err();
+        serde_json::from_value::<Foo>(json!("")).unwrap_err();
+        serde_json::from_value::<Foo>(json!(2)).unwrap_err();
+        serde_json::from_value::<Foo>(json!(-1)).unwrap_err();
+        serde_json::from_value::<Foo>(json!(1.0)).unwrap_err();
+        serde_json::from_value::<Foo>(json!(null)).unwrap_err();
+    }
+
+    struct Custom;
+
+    impl sa::BoolTokens for Custom {
+        const TRUE: &'static [&'static str] = &["enabled"];
+        const FALSE: &'static [&'static str] = &["disabled"];
+    }
+
+    struct Levels;
+
+    impl sa::BoolTokens for Levels {
+        const TRUE: &'static [&'static str] = &["high"];
+        const FALSE: &'static [&'static str] = &["low"];
+        const TRUE_NUMBERS: &'static [i64] = &[1, 5];
+        const FALSE_NUMBERS: &'static [i64] = &[-1];
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Level(#[serde(with = "sa::FlexBoolWith::<Levels>")] bool);
+
+    #[test]
+    fn custom_numbers_are_accepted() {
+        assert_eq!(
+            serde_json::from_value::<Level>(json!(5)).unwrap(),
+            Level(true)
+        );
+        assert_eq!(
+            serde_json::from_value::<Level>(json!(-1)).unwrap(),
+            Level(false)
+        );
+        serde_json::from_value::<Level>(json!(0)).unwrap_err();
+        serde_json::from_value::<Level>(json!(u64::MAX)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Flags(#[serde(with = "sa::Seq::<sa::FlexBoolWith<Custom>>")] Vec<bool>);
+
+    #[test]
+    fn custom_tokens_are_accepted() {
+        let v = serde_json::from_value::<Flags>(json!(["Enabled", "disabled", 1, true])).unwrap();
+        assert_eq!(v, Flags(vec![true, false, true, true]));
+        serde_json::from_value::<Flags>(json!(["yes"])).unwrap_err();
+        check_serialization(Flags(vec![true, false]), json!([true, false]));
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        value::{F64Deserializer, I64Deserializer, StrDeserializer, U64Deserializer},
+        Visitor,
+    },
+    Deserializer, Serializer,
+};
+
+/// Adapter for floating-point numbers that may not be finite
+///
+/// Non-finite values are serialized as the strings `"NaN"`, `"inf"` and `"-inf"`, which formats
+/// like JSON can represent unlike the values themselves. Finite values are serialized with `F`.
+///
+/// The mode `M` selects what is accepted when deserializing:
+/// - [`StringAlways`] requires a string. Strings other than the non-finite tokens are deserialized
+///   with `F`, which must then accept strings, as [`Str`](crate::Str) does.
+/// - [`NumberWhenFinite`] accepts a string or a number, and forwards numbers and other strings to
+///   `F`. Since a number can only be told apart from a string when the format is self-describing,
+///   this mode does not support other formats. It is typically used with `F` being
+///   [`Id`](crate::Id).
+///
+/// `f32` and `f64` are supported.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Float::<sa::Str>")]
+///     text: f64,
+///     #[serde(with = "sa::Float::<sa::Id, sa::NumberWhenFinite>")]
+///     number: f64,
+/// }
+///
+/// let foo = Foo { text: 1.5, number: f64::NEG_INFINITY };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "text": "1.5", "number": "-inf" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct Float<F = crate::Str, M = StringAlways>(PhantomData<(F, M)>);
+
+impl<F, M> Float<F, M> {
+    crate::adapter_methods!(pub);
+}
+
+/// Mode selecting what [`Float`] accepts when deserializing
+pub trait FloatMode {
+    /// Whether numbers are accepted in addition to strings
+    const NUMBER_WHEN_FINITE: bool;
+}
+
+/// Mode only accepting strings
+pub struct StringAlways;
+
+impl FloatMode for StringAlways {
+    const NUMBER_WHEN_FINITE: bool = false;
+}
+
+/// Mode accepting numbers for finite values
+pub struct NumberWhenFinite;
+
+impl FloatMode for NumberWhenFinite {
+    const NUMBER_WHEN_FINITE: bool = true;
+}
+
+struct FloatVisitor<F, M, T> {
+    _f: PhantomData<F>,
+    _m: PhantomData<M>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<F, M, T> FloatVisitor<F, M, T> {
+    fn new() -> Self {
+        Self {
+            _f: PhantomData,
+            _m: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl<F, M> SerializeWith<$ty> for Float<F, M>
+            where
+                F: SerializeWith<$ty>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$ty,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    if value.is_nan() {
+                        serializer.serialize_str("NaN")
+                    } else if *value == <$ty>::INFINITY {
+                        serializer.serialize_str("inf")
+                    } else if *value == <$ty>::NEG_INFINITY {
+                        serializer.serialize_str("-inf")
+                    } else {
+                        F::serialize_with(value, serializer)
+                    }
+                }
+            }
+
+            impl<'de, F, M> DeserializeWith<'de, $ty> for Float<F, M>
+            where
+                F: DeserializeWith<'de, $ty>,
+                M: FloatMode,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    if M::NUMBER_WHEN_FINITE {
+                        deserializer.deserialize_any(FloatVisitor::<F, M, $ty>::new())
+                    } else {
+                        deserializer.deserialize_str(FloatVisitor::<F, M, $ty>::new())
+                    }
+                }
+            }
+
+            impl<'de, F, M> Visitor<'de> for FloatVisitor<F, M, $ty>
+            where
+                F: DeserializeWith<'de, $ty>,
+                M: FloatMode,
+            {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if M::NUMBER_WHEN_FINITE {
+                        f.write_str("a number or a string")
+                    } else {
+                        f.write_str("a string")
+                    }
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    match v {
+                        "NaN" => Ok(<$ty>::NAN),
+                        "inf" => Ok(<$ty>::INFINITY),
+                        "-inf" => Ok(<$ty>::NEG_INFINITY),
+                        _ => F::deserialize_with(StrDeserializer::new(v)),
+                    }
+                }
+
+                fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+                }
+
+                fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    F::deserialize_with(F64Deserializer::new(v))
+                }
+
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    F::deserialize_with(I64Deserializer::new(v))
+                }
+
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    F::deserialize_with(U64Deserializer::new(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::Float::<sa::Str>")] f64);
+
+    #[test]
+    fn non_finite_values_roundtrip_as_tokens() {
+        check_serialization(Text(f64::INFINITY), json!("inf"));
+        check_serialization(Text(f64::NEG_INFINITY), json!("-inf"));
+        assert_eq!(serde_json::to_value(Text(f64::NAN)).unwrap(), json!("NaN"));
+        let deserialized = serde_json::from_value::<Text>(json!("NaN")).unwrap();
+        assert!(deserialized.0.is_nan());
+    }
+
+    #[test]
+    fn string_always_rejects_number() {
+        check_serialization(Text(-2.5), json!("-2.5"));
+        serde_json::from_value::<Text>(json!(-2.5)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Number(#[serde(with = "sa::Float::<sa::Id, sa::NumberWhenFinite>")] f32);
+
+    #[test]
+    fn number_when_finite_roundtrips() {
+        check_serialization(Number(0.5), json!(0.5));
+        check_serialization(Number(f32::INFINITY), json!("inf"));
+        let deserialized = serde_json::from_value::<Number>(json!(3)).unwrap();
+        assert_eq!(deserialized, Number(3.0));
+    }
+
+    #[test]
+    fn unknown_token_is_rejected() {
+        serde_json::from_value::<Number>(json!("Infinity")).unwrap_err();
+        serde_json::from_value::<Text>(json!("one")).unwrap_err();
+    }
+
+    #[test]
+    fn non_finite_value_roundtrips_with_bincode() {
+        let serialized = bincode::serialize(&Text(f64::NEG_INFINITY)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Text>(&serialized).unwrap(),
+            Text(f64::NEG_INFINITY)
+        );
+    }
+}
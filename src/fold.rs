@@ -0,0 +1,267 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, WithEncoding};
+use core::{fmt, marker::PhantomData, time::Duration};
+use serde::{
+    de::{Error as _, SeqAccess, Visitor},
+    Deserializer,
+};
+
+/// Adapter to deserialize a sequence by folding its items with `Op`
+///
+/// Items are deserialized with `F` and combined as they are read, so the sequence is never
+/// collected. Deserialization fails if combining items fails. This adapter can only be used for
+/// deserialization, as a folded value cannot be turned back into the original sequence.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// struct Product;
+///
+/// impl sa::FoldOp<u64> for Product {
+///     type Item = u64;
+///     type Error = &'static str;
+///
+///     fn init() -> u64 {
+///         1
+///     }
+///
+///     fn combine(acc: u64, item: u64) -> Result<u64, &'static str> {
+///         acc.checked_mul(item).ok_or("product overflows")
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Foo(#[serde(with = "sa::FoldWith::<Product, sa::Str>")] u64);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!(["2", "3", "7"])).unwrap();
+/// assert_eq!(deserialized, Foo(42));
+/// assert!(serde_json::from_value::<Foo>(json!(["4294967296", "4294967296"])).is_err());
+/// ```
+pub struct FoldWith<Op, F = crate::Id>(PhantomData<(Op, F)>);
+
+/// Adapter to deserialize a sequence as the sum of its items
+///
+/// See [`FoldWith`] and [`SumOp`].
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Foo(#[serde(with = "sa::Fold::<sa::Id>")] u32);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!([1, 2, 3])).unwrap();
+/// assert_eq!(deserialized, Foo(6));
+/// ```
+pub type Fold<F = crate::Id> = FoldWith<SumOp, F>;
+
+impl<Op, F> FoldWith<Op, F> {
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Folding operation used by [`FoldWith`]
+pub trait FoldOp<A> {
+    /// Type of folded items
+    type Item;
+    /// Error combining the accumulator with an item
+    type Error: fmt::Display;
+
+    /// Returns the initial accumulator
+    fn init() -> A;
+
+    /// Combines the accumulator with an item
+    fn combine(acc: A, item: Self::Item) -> Result<A, Self::Error>;
+}
+
+/// Folding operation adding items to an accumulator starting at its default value
+///
+/// Addition is checked, and an overflow results in an error.
+pub struct SumOp;
+
+impl<A> FoldOp<A> for SumOp
+where
+    A: Summand + Default,
+{
+    type Item = A;
+    type Error = SumOverflow;
+
+    fn init() -> A {
+        A::default()
+    }
+
+    fn combine(acc: A, item: A) -> Result<A, SumOverflow> {
+        acc.checked_add(item).ok_or(SumOverflow)
+    }
+}
+
+/// Value that can be added by [`SumOp`]
+pub trait Summand: Sized {
+    /// Adds two values, returning `None` on overflow
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_summand {
+    ($($t:ty),*) => {
+        $(
+            impl Summand for $t {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_summand!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, Duration);
+
+impl Summand for f32 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+impl Summand for f64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Error returned by [`SumOp`] on overflow
+#[derive(Debug)]
+pub struct SumOverflow;
+
+impl fmt::Display for SumOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sum overflows")
+    }
+}
+
+impl<'de, Op, F, A> DeserializeWith<'de, A> for FoldWith<Op, F>
+where
+    Op: FoldOp<A>,
+    F: DeserializeWith<'de, Op::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<A, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FoldVisitor::<Op, F, A>::new())
+    }
+}
+
+struct FoldVisitor<Op, F, A> {
+    _adapter: PhantomData<(Op, F)>,
+    _acc: PhantomData<fn() -> A>,
+}
+
+impl<Op, F, A> FoldVisitor<Op, F, A> {
+    fn new() -> Self {
+        Self {
+            _adapter: PhantomData,
+            _acc: PhantomData,
+        }
+    }
+}
+
+impl<'de, Op, F, A> Visitor<'de> for FoldVisitor<Op, F, A>
+where
+    Op: FoldOp<A>,
+    F: DeserializeWith<'de, Op::Item>,
+{
+    type Value = A;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let mut acc = Op::init();
+        while let Some(item) = seq.next_element::<WithEncoding<F, Op::Item>>()? {
+            acc = Op::combine(acc, item.into_inner()).map_err(S::Error::custom)?;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as sa;
+    use core::convert::Infallible;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Total(#[serde(with = "sa::Fold::<sa::Str>")] i32);
+
+    #[test]
+    fn fold_sums_items() {
+        let deserialized = serde_json::from_value::<Total>(json!(["1", "-2", "4"])).unwrap();
+        assert_eq!(deserialized, Total(3));
+    }
+
+    #[test]
+    fn fold_of_empty_sequence_is_default() {
+        let deserialized = serde_json::from_value::<Total>(json!([])).unwrap();
+        assert_eq!(deserialized, Total(0));
+    }
+
+    #[test]
+    fn fold_fails_on_invalid_item() {
+        serde_json::from_value::<Total>(json!(["1", 2])).unwrap_err();
+    }
+
+    #[test]
+    fn fold_fails_on_overflow() {
+        let e = serde_json::from_value::<Total>(json!(["2147483647", "1"])).unwrap_err();
+        assert_eq!(e.to_string(), "sum overflows");
+    }
+
+    struct Max;
+
+    impl sa::FoldOp<Option<u32>> for Max {
+        type Item = u32;
+        type Error = Infallible;
+
+        fn init() -> Option<u32> {
+            None
+        }
+
+        fn combine(acc: Option<u32>, item: u32) -> Result<Option<u32>, Infallible> {
+            Ok(Some(acc.map_or(item, |acc| acc.max(item))))
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Highest(#[serde(with = "sa::FoldWith::<Max>")] Option<u32>);
+
+    #[test]
+    fn fold_with_custom_operation() {
+        let deserialized = serde_json::from_value::<Highest>(json!([3, 8, 5])).unwrap();
+        assert_eq!(deserialized, Highest(Some(8)));
+        let deserialized = serde_json::from_value::<Highest>(json!([])).unwrap();
+        assert_eq!(deserialized, Highest(None));
+    }
+
+    #[test]
+    fn fold_works_with_bincode() {
+        let serialized = bincode::serialize(&[3u32, 8, 5][..]).unwrap();
+        let deserialized = bincode::deserialize::<Highest>(&serialized).unwrap();
+        assert_eq!(deserialized, Highest(Some(8)));
+    }
+}
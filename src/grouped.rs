@@ -0,0 +1,223 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{int_parts::IntParts, DeserializeWith, SerializeWith};
+use core::fmt::{self, Display, Write};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to serialize integers as strings of decimal digits grouped by thousands with commas
+///
+/// See [`GroupedBy`] for details.
+pub type Grouped = GroupedBy<','>;
+
+/// Adapter to serialize integers as strings of decimal digits grouped by thousands
+///
+/// Groups of three digits are separated by `SEP`. The [`Grouped`] alias uses a comma, e.g.
+/// `1000000` is serialized as `"1,000,000"`. Negative numbers start with a `-` sign.
+///
+/// When deserializing, all occurrences of `SEP` are removed before parsing the digits, so both
+/// grouped and plain digits are accepted, regardless of where separators appear.
+///
+/// This adapter is meant for human-readable formats, e.g. to render tables when combined with
+/// [`Seq`](crate::Seq).
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Grouped")]
+///     population: u64,
+///     #[serde(with = "sa::GroupedBy::<'.'>")]
+///     balance: i32,
+/// }
+///
+/// let foo = Foo { population: 8_100_000_000, balance: -12_345 };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "population": "8,100,000,000", "balance": "-12.345" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// let plain = json!({ "population": "8100000000", "balance": "-12345" });
+/// assert_eq!(serde_json::from_value::<Foo>(plain).unwrap(), foo);
+/// ```
+pub struct GroupedBy<const SEP: char>;
+
+impl<const SEP: char> GroupedBy<SEP> {
+    crate::adapter_methods!(pub);
+}
+
+macro_rules! impl_grouped {
+    ($($t:ty),*) => {
+        $(
+            impl<const SEP: char> SerializeWith<$t> for GroupedBy<SEP> {
+                fn serialize_with<S: Serializer>(
+                    &value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let (negative, magnitude) = value.into_parts();
+                    serializer.collect_str(&GroupedDisplay::<SEP> {
+                        negative,
+                        magnitude,
+                    })
+                }
+            }
+
+            impl<'de, const SEP: char> DeserializeWith<'de, $t> for GroupedBy<SEP> {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let (negative, magnitude) =
+                        deserializer.deserialize_str(GroupedVisitor::<SEP>::new(<$t>::SIGNED))?;
+                    <$t>::from_parts(negative, magnitude)
+                        .ok_or_else(|| serde::de::Error::custom("number out of range"))
+                }
+            }
+        )*
+    };
+}
+
+impl_grouped!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Maximum number of decimal digits in a 128-bit number
+const MAX_DIGITS: usize = 39;
+
+struct GroupedDisplay<const SEP: char> {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl<const SEP: char> Display for GroupedDisplay<SEP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = [0u8; MAX_DIGITS];
+        let mut start = digits.len();
+        let mut n = self.magnitude;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        if self.negative {
+            f.write_char('-')?;
+        }
+        let digits = &digits[start..];
+        digits.iter().enumerate().try_for_each(|(i, &d)| {
+            if i != 0 && (digits.len() - i) % 3 == 0 {
+                f.write_char(SEP)?;
+            }
+            f.write_char(d.into())
+        })
+    }
+}
+
+struct GroupedVisitor<const SEP: char> {
+    signed: bool,
+}
+
+impl<const SEP: char> GroupedVisitor<SEP> {
+    fn new(signed: bool) -> Self {
+        Self { signed }
+    }
+}
+
+impl<const SEP: char> Visitor<'_> for GroupedVisitor<SEP> {
+    type Value = (bool, u128);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a string of decimal digits optionally grouped with {SEP:?}"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let (negative, grouped) = match v.strip_prefix('-') {
+            Some(grouped) if self.signed => (true, grouped),
+            _ => (false, v),
+        };
+        let mut digits = [0u8; MAX_DIGITS];
+        let mut len = 0;
+        for c in grouped.chars().filter(|&c| c != SEP) {
+            match (c.to_digit(10), digits.get_mut(len)) {
+                (Some(d), Some(slot)) => *slot = b'0' + d as u8,
+                (Some(_), None) => return Err(E::custom("number out of range")),
+                (None, _) => return Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+            }
+            len += 1;
+        }
+        if len == 0 {
+            return Err(E::invalid_value(serde::de::Unexpected::Str(v), &self));
+        }
+        let digits = core::str::from_utf8(&digits[..len]).map_err(E::custom)?;
+        let magnitude = digits.parse::<u128>().map_err(E::custom)?;
+        Ok((negative, magnitude))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::Grouped")] i64);
+
+    #[test]
+    fn digits_are_grouped_by_thousands() {
+        check_serialization(Foo(0), json!("0"));
+        check_serialization(Foo(999), json!("999"));
+        check_serialization(Foo(1000), json!("1,000"));
+        check_serialization(Foo(-1_234_567), json!("-1,234,567"));
+        check_serialization(Foo(i64::MIN), json!("-9,223,372,036,854,775,808"));
+    }
+
+    #[test]
+    fn plain_digits_are_accepted() {
+        let v = serde_json::from_value::<Foo>(json!("-1234567")).unwrap();
+        assert_eq!(v, Foo(-1_234_567));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Spaced(#[serde(with = "crate::GroupedBy::<' '>")] u128);
+
+    #[test]
+    fn custom_separator_roundtrips() {
+        check_serialization(
+            Spaced(u128::MAX),
+            json!("340 282 366 920 938 463 463 374 607 431 768 211 455"),
+        );
+    }
+
+    #[test]
+    fn invalid_grouped_number_fails() {
+        serde_json::from_value::<Foo>(json!("1.000")).unwrap_err();
+        serde_json::from_value::<Foo>(json!(",")).unwrap_err();
+        serde_json::from_value::<Foo>(json!("--1")).unwrap_err();
+        serde_json::from_value::<Spaced>(json!("-1")).unwrap_err();
+    }
+
+    #[test]
+    fn out_of_range_grouped_number_fails() {
+        serde_json::from_value::<Foo>(json!("9,223,372,036,854,775,808")).unwrap_err();
+        serde_json::from_value::<Spaced>(json!(
+            "1 000 000 000 000 000 000 000 000 000 000 000 000 000"
+        ))
+        .unwrap_err();
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Seq, SerializeWith, WithEncoding};
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter for [`BinaryHeap`] with deterministic output
+///
+/// The heap is serialized as a sequence of its items sorted in ascending order, each item being
+/// serialized with `F`. Deserialization collects a sequence of items deserialized with `F`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::BinaryHeap;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::Heap::<serdapt::Str>")] BinaryHeap<i32>);
+///
+/// let foo = Foo(BinaryHeap::from([3, 1, 2]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2", "3"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized.0.into_sorted_vec(), [1, 2, 3]);
+/// # }
+/// ```
+pub struct Heap<F = crate::Id>(PhantomData<F>);
+
+impl<F> Heap<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<BinaryHeap<T>> for Heap<F>
+where
+    F: SerializeWith<T>,
+    T: Ord,
+{
+    fn serialize_with<S: Serializer>(
+        value: &BinaryHeap<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut items = value.iter().collect::<Vec<_>>();
+        items.sort_unstable();
+        serializer.collect_seq(items.into_iter().map(WithEncoding::<&F, _>::from))
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, BinaryHeap<T>> for Heap<F>
+where
+    F: DeserializeWith<'de, T>,
+    T: Ord,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<BinaryHeap<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Seq::<F>::deserialize_with(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{collections::BinaryHeap, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Foo(#[serde(with = "sa::Heap::<sa::Str>")] BinaryHeap<i32>);
+
+    impl PartialEq for Foo {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.clone().into_sorted_vec() == other.0.clone().into_sorted_vec()
+        }
+    }
+
+    #[test]
+    fn heap_is_serialized_in_ascending_order() {
+        check_serialization(
+            Foo(BinaryHeap::from([5, 1, 4, 2, 3, 2])),
+            json!(["1", "2", "2", "3", "4", "5"]),
+        );
+    }
+
+    #[test]
+    fn serialized_form_does_not_depend_on_insertion_order() {
+        let a = Foo((0..100).collect());
+        let b = Foo((0..100).rev().collect());
+        assert_eq!(
+            serde_json::to_value(&a).unwrap(),
+            serde_json::to_value(&b).unwrap(),
+        );
+    }
+
+    #[test]
+    fn empty_heap_roundtrips() {
+        check_serialization(Foo(BinaryHeap::new()), json!([]));
+    }
+
+    #[test]
+    fn unsorted_input_is_accepted() {
+        let deserialized = serde_json::from_value::<Foo>(json!(["3", "1", "2"])).unwrap();
+        assert_eq!(deserialized.0.into_sorted_vec(), Vec::from([1, 2, 3]));
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, vec::Vec};
+use core::fmt::{self, Display, Write};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to serialize bytes as a lowercase hexadecimal string
+///
+/// See [`HexEncoding`] for details.
+pub type Hex = HexEncoding<false>;
+
+/// Adapter to serialize bytes as an uppercase hexadecimal string
+///
+/// See [`HexEncoding`] for details.
+pub type UpperHex = HexEncoding<true>;
+
+/// Adapter to serialize bytes as a hexadecimal string in human-readable formats and as bytes
+/// otherwise
+///
+/// This is a shorthand for [`HumanOr<Hex, Bytes>`](crate::HumanOr):
+/// - Human-readable formats like JSON get a lowercase hexadecimal string, as with [`Hex`].
+/// - Binary formats like bincode get [`Bytes`](crate::Bytes), which is compact in formats that
+///   support serde bytes. Formats without native bytes usually fall back to a sequence of `u8`.
+///
+/// Supported types are those supported by both [`Hex`] and [`Bytes`](crate::Bytes). In
+/// particular, borrowed slices are not supported.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::SmartBytes")] Vec<u8>);
+///
+/// let foo = Foo(vec![0x01, 0xab]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("01ab"));
+/// let serialized = bincode::serialize(&foo).unwrap();
+/// assert_eq!(serialized, [2, 0, 0, 0, 0, 0, 0, 0, 0x01, 0xab]);
+/// let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub type SmartBytes = crate::HumanOr<Hex, crate::Bytes>;
+
+/// Adapter to serialize bytes as a hexadecimal string
+///
+/// Bytes are serialized with lowercase digits, or uppercase digits if `UPPERCASE` is `true`. The
+/// [`Hex`] and [`UpperHex`] aliases are provided for convenience. Both cases are accepted when
+/// deserializing. An odd number of digits or any character that is not a
+/// hexadecimal digit results in an error.
+///
+/// This adapter supports the same types as [`Bytes`](crate::Bytes), except borrowed slices as
+/// the decoded bytes cannot borrow from the serialized string.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::HumanOr::<sa::Hex, sa::Bytes>")]
+///     bytes: Vec<u8>,
+///     #[serde(with = "sa::UpperHex")]
+///     key: [u8; 2],
+/// }
+///
+/// let foo = Foo { bytes: vec![0x01, 0xab], key: [0xcd, 0xef] };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "bytes": "01ab", "key": "CDEF" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct HexEncoding<const UPPERCASE: bool>;
+
+impl<const UPPERCASE: bool> HexEncoding<UPPERCASE> {
+    /// Serializes value as a hexadecimal string
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value from a hexadecimal string
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<const UPPERCASE: bool, T> SerializeWith<T> for HexEncoding<UPPERCASE>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&HexDisplay::<UPPERCASE>(value.as_ref()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const UPPERCASE: bool> DeserializeWith<'de, Vec<u8>> for HexEncoding<UPPERCASE> {
+    fn deserialize_with<D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(VecVisitor)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const UPPERCASE: bool> DeserializeWith<'de, Box<[u8]>> for HexEncoding<UPPERCASE> {
+    fn deserialize_with<D>(deserializer: D) -> Result<Box<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const UPPERCASE: bool> DeserializeWith<'de, Rc<[u8]>> for HexEncoding<UPPERCASE> {
+    fn deserialize_with<D>(deserializer: D) -> Result<Rc<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<'de, const UPPERCASE: bool> DeserializeWith<'de, alloc::sync::Arc<[u8]>>
+    for HexEncoding<UPPERCASE>
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<alloc::sync::Arc<[u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, 'a, const UPPERCASE: bool> DeserializeWith<'de, Cow<'a, [u8]>>
+    for HexEncoding<UPPERCASE>
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Cow<'a, [u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Self as DeserializeWith<'de, Vec<u8>>>::deserialize_with(deserializer).map(Cow::Owned)
+    }
+}
+
+impl<'de, const UPPERCASE: bool, const N: usize> DeserializeWith<'de, [u8; N]>
+    for HexEncoding<UPPERCASE>
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ArrayVisitor::<N>)
+    }
+}
+
+struct HexDisplay<'a, const UPPERCASE: bool>(&'a [u8]);
+
+impl<const UPPERCASE: bool> Display for HexDisplay<'_, UPPERCASE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = if UPPERCASE {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        self.0.iter().try_for_each(|&b| {
+            f.write_char(digits[usize::from(b >> 4)].into())?;
+            f.write_char(digits[usize::from(b & 0xf)].into())
+        })
+    }
+}
+
+fn decode<E>(s: &str) -> Result<impl Iterator<Item = Result<u8, E>> + '_, E>
+where
+    E: serde::de::Error,
+{
+    let pairs = s.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err(E::custom("odd number of hexadecimal digits"));
+    }
+    let digit = |i: usize, c: u8| {
+        (c as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or_else(|| E::custom(format_args!("invalid hexadecimal digit at index {i}")))
+    };
+    Ok(pairs
+        .enumerate()
+        .map(move |(i, pair)| Ok(digit(2 * i, pair[0])? << 4 | digit(2 * i + 1, pair[1])?)))
+}
+
+#[cfg(feature = "alloc")]
+struct VecVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for VecVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hexadecimal string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut bytes = Vec::with_capacity(v.len() / 2);
+        for b in decode(v)? {
+            bytes.push(b?);
+        }
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+struct ArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a hexadecimal string of {N} bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let decoded = decode(v)?;
+        if v.len() != 2 * N {
+            return Err(E::invalid_length(v.len() / 2, &self));
+        }
+        let mut bytes = [0u8; N];
+        bytes
+            .iter_mut()
+            .zip(decoded)
+            .try_for_each(|(out, b)| b.map(|b| *out = b))?;
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct VecWrapper(#[serde(with = "crate::Hex")] Vec<u8>);
+
+    #[test]
+    fn hex_vec_roundtrips() {
+        check_serialization(VecWrapper(vec![0x00, 0x1f, 0xa0, 0xff]), json!("001fa0ff"));
+    }
+
+    #[test]
+    fn empty_hex_vec_roundtrips() {
+        check_serialization(VecWrapper(Vec::new()), json!(""));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct UpperWrapper(#[serde(with = "crate::UpperHex")] Vec<u8>);
+
+    #[test]
+    fn uppercase_hex_roundtrips() {
+        check_serialization(UpperWrapper(vec![0xab, 0xcd]), json!("ABCD"));
+    }
+
+    #[test]
+    fn hex_accepts_both_cases() {
+        let v = serde_json::from_value::<VecWrapper>(json!("aBcD")).unwrap();
+        assert_eq!(v, VecWrapper(vec![0xab, 0xcd]));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct ArrayWrapper<const N: usize>(#[serde(with = "crate::Hex")] [u8; N]);
+
+    #[test]
+    fn hex_array_roundtrips() {
+        check_serialization(ArrayWrapper([1, 2, 3]), json!("010203"));
+    }
+
+    #[test]
+    fn hex_array_with_wrong_length_fails() {
+        serde_json::from_value::<ArrayWrapper<2>>(json!("010203")).unwrap_err();
+    }
+
+    #[test]
+    fn odd_hex_string_fails() {
+        serde_json::from_value::<VecWrapper>(json!("123")).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_hex_digit_fails() {
+        serde_json::from_value::<VecWrapper>(json!("0g")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Smart(#[serde(with = "crate::SmartBytes")] Vec<u8>);
+
+    #[test]
+    fn smart_bytes_use_hex_in_human_readable_formats() {
+        check_serialization(Smart(vec![0x00, 0x1f, 0xa0]), json!("001fa0"));
+    }
+
+    #[derive(Serialize)]
+    struct Raw(#[serde(with = "crate::Bytes")] Vec<u8>);
+
+    #[test]
+    fn smart_bytes_use_bytes_in_binary_formats() {
+        let original = Smart(vec![0x00, 0x1f, 0xa0]);
+        let serialized = bincode::serialize(&original).unwrap();
+        let expected = bincode::serialize(&Raw(vec![0x00, 0x1f, 0xa0])).unwrap();
+        assert_eq!(serialized, expected);
+        let deserialized = bincode::deserialize::<Smart>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, time::Duration};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to serialize [`Duration`] as a human-readable string such as `"1h 30m"`
+///
+/// Formatting and parsing are done with the [`humantime`] crate. Parsing also accepts strings
+/// without spaces such as `"1h30m"`, and fails with a custom error on invalid strings.
+///
+/// Combined with [`HumanOr`](crate::HumanOr), durations can be readable in configuration files
+/// while remaining compact in binary formats.
+///
+/// This adapter is available with the `humantime` feature.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::time::Duration;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Config {
+///     #[serde(with = "sa::HumanOr::<sa::HumanDuration, sa::Id>")]
+///     timeout: Duration,
+/// }
+///
+/// let config = Config { timeout: Duration::from_secs(5400) };
+/// let serialized = serde_json::to_value(&config).unwrap();
+/// assert_eq!(serialized, json!({ "timeout": "1h 30m" }));
+/// let deserialized = serde_json::from_value::<Config>(json!({ "timeout": "1h30m" })).unwrap();
+/// assert_eq!(deserialized, config);
+/// let serialized = bincode::serialize(&config).unwrap();
+/// assert_eq!(bincode::deserialize::<Config>(&serialized).unwrap(), config);
+/// ```
+pub struct HumanDuration;
+
+impl HumanDuration {
+    crate::adapter_methods!(pub);
+}
+
+impl SerializeWith<Duration> for HumanDuration {
+    fn serialize_with<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&humantime::format_duration(*value))
+    }
+}
+
+impl<'de> DeserializeWith<'de, Duration> for HumanDuration {
+    fn deserialize_with<D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(HumanDurationVisitor)
+    }
+}
+
+struct HumanDurationVisitor;
+
+impl Visitor<'_> for HumanDurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a duration such as \"1h 30m\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        humantime::parse_duration(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use core::time::Duration;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::HumanDuration")] Duration);
+
+    #[test]
+    fn duration_roundtrips_as_string() {
+        check_serialization(Foo(Duration::from_millis(90_500)), json!("1m 30s 500ms"));
+        check_serialization(Foo(Duration::ZERO), json!("0s"));
+    }
+
+    #[test]
+    fn invalid_duration_is_rejected() {
+        serde_json::from_value::<Foo>(json!("soon")).unwrap_err();
+        serde_json::from_value::<Foo>(json!(30)).unwrap_err();
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt::Display, marker::PhantomData};
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to check that a value deserialized with `F` lies within the bounds `B`
+///
+/// Bounds are inclusive and provided by implementing [`RangeBound`]. Deserialization fails if the
+/// value is out of bounds. Serialization is forwarded to `F` unchanged.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct Percentage;
+///
+/// impl sa::RangeBound for Percentage {
+///     type Value = u8;
+///     const MIN: u8 = 0;
+///     const MAX: u8 = 100;
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::InRange::<Percentage, sa::Str>")] u8);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!("42")).unwrap();
+/// assert_eq!(deserialized, Foo(42));
+/// assert!(serde_json::from_value::<Foo>(json!("101")).is_err());
+/// ```
+pub struct InRange<B, F = crate::Id>(PhantomData<(B, F)>);
+
+impl<B, F> InRange<B, F> {
+    crate::adapter_methods!(pub);
+}
+
+/// Inclusive bounds used by [`InRange`]
+pub trait RangeBound {
+    /// Type of bounded values
+    type Value;
+
+    /// Lower bound
+    const MIN: Self::Value;
+
+    /// Upper bound
+    const MAX: Self::Value;
+}
+
+impl<B, F, T> SerializeWith<T> for InRange<B, F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, B, F, T> DeserializeWith<'de, T> for InRange<B, F>
+where
+    B: RangeBound<Value = T>,
+    F: DeserializeWith<'de, T>,
+    T: Display + PartialOrd,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = F::deserialize_with(deserializer)?;
+        if B::MIN <= value && value <= B::MAX {
+            Ok(value)
+        } else {
+            Err(D::Error::custom(format_args!(
+                "expected a value between {} and {}, got {value}",
+                B::MIN,
+                B::MAX
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    struct Port;
+
+    impl sa::RangeBound for Port {
+        type Value = u32;
+        const MIN: u32 = 1;
+        const MAX: u32 = 65535;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::InRange::<Port>")] u32);
+
+    #[test]
+    fn value_within_bounds_is_accepted() {
+        check_serialization(Foo(1), json!(1));
+        check_serialization(Foo(65535), json!(65535));
+    }
+
+    #[test]
+    fn value_out_of_bounds_is_rejected() {
+        serde_json::from_value::<Foo>(json!(0)).unwrap_err();
+        serde_json::from_value::<Foo>(json!(65536)).unwrap_err();
+    }
+
+    struct Unit;
+
+    impl sa::RangeBound for Unit {
+        type Value = f64;
+        const MIN: f64 = -1.0;
+        const MAX: f64 = 1.0;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Bar(#[serde(with = "sa::InRange::<Unit, sa::Str>")] f64);
+
+    #[test]
+    fn nan_is_rejected() {
+        check_serialization(Bar(0.5), json!("0.5"));
+        serde_json::from_value::<Bar>(json!("NaN")).unwrap_err();
+    }
+}
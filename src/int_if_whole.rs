@@ -0,0 +1,145 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize a float as an integer when it has no fractional part
+///
+/// A float with no fractional part within the range of `i64` is converted to `i64` and serialized
+/// with `F`. Any other float, including non-finite ones, is serialized as a float with `F`. This
+/// avoids a trailing `.0` in formats like JSON, so `2.0` is serialized as `2` and `2.5` as `2.5`.
+/// The output is thus asymmetric, numbers of both kinds appearing depending on the value. Negative
+/// zero is serialized as `0` and deserialized as positive zero.
+///
+/// When deserializing, the float is deserialized with `F`, which accepts both integers and floats
+/// with [`Id`](crate::Id) and [`Str`](crate::Str).
+///
+/// Floats are only converted to integers for human-readable formats, as non-self-describing formats
+/// could not tell integers and floats apart when deserializing. Other formats always get floats.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Seq::<sa::IntIfWhole<sa::Id>>")] Vec<f64>);
+///
+/// let foo = Foo(vec![2.0, 2.5, -3.0]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized.to_string(), "[2,2.5,-3]");
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct IntIfWhole<F = crate::Id>(PhantomData<F>);
+
+impl<F> IntIfWhole<F> {
+    crate::adapter_methods!(pub);
+}
+
+/// `2^63` as a float, the smallest float above the range of `i64`
+const I64_BOUND: f64 = 9_223_372_036_854_775_808.0;
+
+fn whole(x: f64) -> Option<i64> {
+    if (-I64_BOUND..I64_BOUND).contains(&x) && x == (x as i64) as f64 {
+        Some(x as i64)
+    } else {
+        None
+    }
+}
+
+macro_rules! impl_int_if_whole {
+    ($($t:ty),*) => {
+        $(
+            impl<F> SerializeWith<$t> for IntIfWhole<F>
+            where
+                F: SerializeWith<i64> + SerializeWith<$t>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    match whole(f64::from(*value)) {
+                        Some(n) if serializer.is_human_readable() => {
+                            <F as SerializeWith<i64>>::serialize_with(&n, serializer)
+                        }
+                        _ => <F as SerializeWith<$t>>::serialize_with(value, serializer),
+                    }
+                }
+            }
+
+            impl<'de, F> DeserializeWith<'de, $t> for IntIfWhole<F>
+            where
+                F: DeserializeWith<'de, $t>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    F::deserialize_with(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_if_whole!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::IntIfWhole::<sa::Id>")] f64);
+
+    #[test]
+    fn whole_float_is_serialized_as_integer() {
+        check_serialization(Foo(3.0), json!(3));
+        check_serialization(Foo(-3.0), json!(-3));
+        check_serialization(Foo(0.0), json!(0));
+        assert!(serde_json::to_value(Foo(3.0)).unwrap().is_i64());
+    }
+
+    #[test]
+    fn fractional_float_is_serialized_as_float() {
+        check_serialization(Foo(3.5), json!(3.5));
+    }
+
+    #[test]
+    fn float_beyond_integer_range_is_serialized_as_float() {
+        check_serialization(Foo(1e19), json!(1e19));
+        check_serialization(Foo(-1e19), json!(-1e19));
+        assert!(serde_json::to_value(Foo(i64::MIN as f64)).unwrap().is_i64());
+    }
+
+    #[test]
+    fn whole_float_is_serialized_as_float_in_binary_format() {
+        let serialized = bincode::serialize(&Foo(3.0)).unwrap();
+        assert_eq!(serialized, 3.0f64.to_le_bytes());
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, Foo(3.0));
+    }
+
+    #[test]
+    fn non_finite_float_is_passed_through() {
+        let serialized = bincode::serialize(&Foo(f64::INFINITY)).unwrap();
+        assert_eq!(serialized, f64::INFINITY.to_le_bytes());
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, Foo(f64::INFINITY));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::IntIfWhole::<sa::Str>")] f32);
+
+    #[test]
+    fn whole_float_is_serialized_as_integer_string() {
+        check_serialization(Text(3.0), json!("3"));
+        check_serialization(Text(0.5), json!("0.5"));
+    }
+}
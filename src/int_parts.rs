@@ -0,0 +1,53 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+/// Conversion of primitive integers to and from a sign and a magnitude
+pub(crate) trait IntParts: Sized {
+    /// Whether the type can hold negative numbers
+    const SIGNED: bool;
+
+    /// Splits `self` into whether it is negative and its magnitude
+    fn into_parts(self) -> (bool, u128);
+
+    /// Builds a number from a sign and a magnitude, or `None` if it is out of range
+    fn from_parts(negative: bool, magnitude: u128) -> Option<Self>;
+}
+
+macro_rules! impl_int_parts {
+    ($($t:ty => |$x:ident| $parts:expr, $signed:literal,)*) => {
+        $(
+            impl IntParts for $t {
+                const SIGNED: bool = $signed;
+
+                fn into_parts(self) -> (bool, u128) {
+                    let $x = self;
+                    $parts
+                }
+
+                fn from_parts(negative: bool, magnitude: u128) -> Option<Self> {
+                    if negative {
+                        0i128
+                            .checked_sub_unsigned(magnitude)
+                            .and_then(|n| Self::try_from(n).ok())
+                    } else {
+                        Self::try_from(magnitude).ok()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int_parts! {
+    u8 => |x| (false, u128::from(x)), false,
+    u16 => |x| (false, u128::from(x)), false,
+    u32 => |x| (false, u128::from(x)), false,
+    u64 => |x| (false, u128::from(x)), false,
+    u128 => |x| (false, x), false,
+    usize => |x| (false, x as u128), false,
+    i8 => |x| (x < 0, u128::from(x.unsigned_abs())), true,
+    i16 => |x| (x < 0, u128::from(x.unsigned_abs())), true,
+    i32 => |x| (x < 0, u128::from(x.unsigned_abs())), true,
+    i64 => |x| (x < 0, u128::from(x.unsigned_abs())), true,
+    i128 => |x| (x < 0, x.unsigned_abs()), true,
+    isize => |x| (x < 0, x.unsigned_abs() as u128), true,
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+/// Returns whether a value is equal to its type's default value
+///
+/// This is meant to be used with `#[serde(skip_serializing_if = "serdapt::is_default")]` to omit
+/// fields holding their default value. The field then also needs `#[serde(default)]` so that it
+/// can be deserialized when missing. Both attributes compose with `#[serde(with = ...)]`, which
+/// only applies to fields that are present. [`DefaultOnNull`](crate::DefaultOnNull) can be used as
+/// well to deserialize null as the default value.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(default, skip_serializing_if = "sa::is_default", with = "sa::Str")]
+///     count: u32,
+///     #[serde(default, skip_serializing_if = "sa::is_default")]
+///     name: String,
+/// }
+///
+/// let foo = Foo { count: 0, name: "foo".to_owned() };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "name": "foo" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub fn is_default<T>(value: &T) -> bool
+where
+    T: Default + PartialEq,
+{
+    *value == T::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo {
+        #[serde(
+            default,
+            skip_serializing_if = "sa::is_default",
+            with = "sa::DefaultOnNull::<sa::Str>"
+        )]
+        n: i32,
+    }
+
+    #[test]
+    fn default_value_is_skipped() {
+        check_serialization(Foo { n: 0 }, json!({}));
+        check_serialization(Foo { n: 3 }, json!({ "n": "3" }));
+    }
+
+    #[test]
+    fn null_is_default_value() {
+        let deserialized = serde_json::from_value::<Foo>(json!({ "n": null })).unwrap();
+        assert_eq!(deserialized, Foo { n: 0 });
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use alloc::string::String;
+use core::marker::PhantomData;
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+
+/// Adapter to serialize a value as a string containing its JSON representation
+///
+/// This adapter is available with the `json` feature. The value is serialized to JSON with `F`
+/// using `serde_json`, and the resulting JSON text is serialized as a string in the outer format,
+/// whatever it is. When deserializing, a string is deserialized and parsed as JSON with `F`.
+/// Errors serializing or parsing the JSON text are reported as custom errors of the outer format.
+///
+/// The value is encoded twice, which takes time and space: the JSON text is built in a temporary
+/// string, and when the outer format is also JSON, every quote and backslash of the embedded text
+/// gets escaped. Deserialization always parses from an owned string, so the value cannot borrow
+/// from the input.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "json")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Row {
+///     id: u32,
+///     #[serde(with = "sa::JsonString::<sa::Id>")]
+///     tags: BTreeMap<String, u32>,
+/// }
+///
+/// let row = Row { id: 1, tags: BTreeMap::from([("a".to_owned(), 2)]) };
+/// let serialized = serde_json::to_value(&row).unwrap();
+/// assert_eq!(serialized, json!({ "id": 1, "tags": r#"{"a":2}"# }));
+/// let deserialized = serde_json::from_value::<Row>(serialized).unwrap();
+/// assert_eq!(deserialized, row);
+/// # }
+/// ```
+pub struct JsonString<F = crate::Id>(PhantomData<F>);
+
+impl<F> JsonString<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<T> for JsonString<F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(&WithEncoding::<&F, &T>::from(value))
+            .map_err(S::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, T> for JsonString<F>
+where
+    F: for<'a> DeserializeWith<'a, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = String::deserialize(deserializer)?;
+        serde_json::from_str::<WithEncoding<F, T>>(&json)
+            .map(WithEncoding::into_inner)
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::JsonString::<sa::Seq<sa::Str>>")] Vec<i32>);
+
+    #[test]
+    fn value_is_embedded_as_json_string() {
+        check_serialization(Foo(vec![1, -2]), json!(r#"["1","-2"]"#));
+    }
+
+    #[test]
+    fn json_string_roundtrips_with_bincode() {
+        let original = Foo(vec![3, 4]);
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(serialized, bincode::serialize(r#"["3","4"]"#).unwrap());
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn invalid_json_is_rejected() {
+        serde_json::from_value::<Foo>(json!("[1,")).unwrap_err();
+        serde_json::from_value::<Foo>(json!("[1]")).unwrap_err();
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{text::TextSerializer, DeserializeWith, SerializeWith, WithEncoding};
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{IntoDeserializer, MapAccess, Visitor},
+    ser::{Error as _, SerializeMap},
+    Deserializer, Serializer,
+};
+
+/// Adapter to convert the case of map keys
+///
+/// Keys are expected to be in `snake_case` in memory. When serializing, keys are serialized with
+/// `F` as text and converted to the case style `Case`. When deserializing, keys are converted back
+/// to `snake_case` and deserialized with `F`. Values are serialized with `G`.
+///
+/// Conversions are not always reversible. For example, both `max_size` and `max_Size` convert to
+/// `maxSize` in camel case, which converts back to `max_size`. Keys that would not round-trip
+/// should be avoided.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(
+///     #[serde(with = "sa::KeyCase::<sa::CamelCase, sa::Str, sa::Id>")] BTreeMap<String, i32>,
+/// );
+///
+/// let foo = Foo(BTreeMap::from_iter([("max_size".to_owned(), 3)]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "maxSize": 3 }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct KeyCase<Case, F, G>(PhantomData<(Case, F, G)>);
+
+impl<Case, F, G> KeyCase<Case, F, G> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Case style used by [`KeyCase`]
+pub trait CaseStyle {
+    /// Converts `snake_case` text to this case style
+    fn from_snake_case(s: &str) -> String;
+
+    /// Converts text in this case style to `snake_case`
+    fn to_snake_case(s: &str) -> String;
+}
+
+/// `snake_case` style
+pub struct SnakeCase;
+
+impl CaseStyle for SnakeCase {
+    fn from_snake_case(s: &str) -> String {
+        s.into()
+    }
+
+    fn to_snake_case(s: &str) -> String {
+        s.into()
+    }
+}
+
+/// `camelCase` style
+pub struct CamelCase;
+
+impl CaseStyle for CamelCase {
+    fn from_snake_case(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut upper = false;
+        for c in s.chars() {
+            match c {
+                '_' if !out.is_empty() => upper = true,
+                _ if upper => {
+                    out.extend(c.to_uppercase());
+                    upper = false;
+                }
+                _ => out.push(c),
+            }
+        }
+        out
+    }
+
+    fn to_snake_case(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            if c.is_uppercase() {
+                if !out.is_empty() {
+                    out.push('_');
+                }
+                out.extend(c.to_lowercase());
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+}
+
+/// `kebab-case` style
+pub struct KebabCase;
+
+impl CaseStyle for KebabCase {
+    fn from_snake_case(s: &str) -> String {
+        s.replace('_', "-")
+    }
+
+    fn to_snake_case(s: &str) -> String {
+        s.replace('-', "_")
+    }
+}
+
+impl<Case, F, G, C, K, V> SerializeWith<C> for KeyCase<Case, F, G>
+where
+    Case: CaseStyle,
+    F: SerializeWith<K>,
+    G: SerializeWith<V>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries = container.into_iter();
+        let len = match entries.size_hint() {
+            (lo, Some(hi)) if lo == hi => Some(lo),
+            _ => None,
+        };
+        let mut map = serializer.serialize_map(len)?;
+        for (k, v) in entries {
+            let key = F::serialize_with(k, TextSerializer).map_err(S::Error::custom)?;
+            map.serialize_entry(
+                &Case::from_snake_case(&key),
+                &WithEncoding::<&G, _>::from(v),
+            )?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, Case, F, G, C, K, V> DeserializeWith<'de, C> for KeyCase<Case, F, G>
+where
+    Case: CaseStyle,
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    C: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(KeyCaseVisitor::<Case, F, G, C>::new())
+    }
+}
+
+struct KeyCaseVisitor<Case, F, G, C> {
+    _f: PhantomData<(Case, F, G)>,
+    _c: PhantomData<fn() -> C>,
+}
+
+impl<Case, F, G, C> KeyCaseVisitor<Case, F, G, C> {
+    fn new() -> Self {
+        Self {
+            _f: PhantomData,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<'de, Case, F, G, C, K, V> Visitor<'de> for KeyCaseVisitor<Case, F, G, C>
+where
+    Case: CaseStyle,
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    C: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map with string keys")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        core::iter::from_fn(|| {
+            map.next_entry::<String, WithEncoding<G, V>>()
+                .and_then(|entry| {
+                    entry
+                        .map(|(k, v)| {
+                            let k = IntoDeserializer::<A::Error>::into_deserializer(
+                                Case::to_snake_case(&k),
+                            );
+                            Ok((F::deserialize_with(k)?, v.into_inner()))
+                        })
+                        .transpose()
+                })
+                .transpose()
+        })
+        .collect()
+    }
+}
+
+#[cfg(all(feature = "std", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn entries<K: Ord>(keys: [K; 2]) -> BTreeMap<K, i32> {
+        keys.into_iter().zip(1..).collect()
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Camel(
+        #[serde(with = "sa::KeyCase::<sa::CamelCase, sa::Str, sa::Id>")] BTreeMap<String, i32>,
+    );
+
+    #[test]
+    fn camel_case_keys_roundtrip() {
+        check_serialization(
+            Camel(entries(["max_size".into(), "id".into()])),
+            json!({ "maxSize": 1, "id": 2 }),
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Kebab(
+        #[serde(with = "sa::KeyCase::<sa::KebabCase, sa::Str, sa::Str>")] BTreeMap<String, i32>,
+    );
+
+    #[test]
+    fn kebab_case_keys_roundtrip() {
+        check_serialization(
+            Kebab(entries(["max_size".into(), "min_size".into()])),
+            json!({ "max-size": "1", "min-size": "2" }),
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Snake(
+        #[serde(with = "sa::KeyCase::<sa::SnakeCase, sa::Str, sa::Id>")] BTreeMap<i32, i32>,
+    );
+
+    #[test]
+    fn snake_case_keys_roundtrip() {
+        check_serialization(Snake(entries([33, 34])), json!({ "33": 1, "34": 2 }));
+    }
+
+    #[test]
+    fn camel_case_conversion_is_lossy() {
+        let original = Camel(entries(["max_Size".into(), "id".into()]));
+        let serialized = serde_json::to_value(&original).unwrap();
+        assert_eq!(serialized, json!({ "maxSize": 1, "id": 2 }));
+        let deserialized = serde_json::from_value::<Camel>(serialized).unwrap();
+        assert_eq!(
+            deserialized,
+            Camel(entries(["max_size".into(), "id".into()]))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{fields, DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize a collection along with its number of elements
+///
+/// The collection is serialized as a struct with fields `len` and `data`. `len` is the number of
+/// elements of the collection as a `u64`, and `data` is the collection serialized with `F`,
+/// typically [`Bytes`](crate::Bytes) or [`Seq`](crate::Seq). When deserializing, `len` must equal
+/// the number of elements of the deserialized collection, otherwise an error is returned. The
+/// number of elements is the number of items yielded when iterating over a reference to the
+/// collection.
+///
+/// This helps interoperate with binary schemas that store a length followed by the elements. With
+/// non-self-describing formats like bincode, the length stored by `F` is still written in addition
+/// to `len`.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Packet(#[serde(with = "sa::LenPrefixed::<sa::Bytes>")] Vec<u8>);
+///
+/// let packet = Packet(vec![1, 2, 3]);
+/// let serialized = serde_json::to_value(&packet).unwrap();
+/// assert_eq!(serialized, json!({ "len": 3, "data": [1, 2, 3] }));
+/// let deserialized = serde_json::from_value::<Packet>(serialized).unwrap();
+/// assert_eq!(deserialized, packet);
+/// assert!(serde_json::from_value::<Packet>(json!({ "len": 2, "data": [1, 2, 3] })).is_err());
+/// ```
+pub struct LenPrefixed<F = crate::Id>(PhantomData<F>);
+
+impl<F> LenPrefixed<F> {
+    crate::adapter_methods!(pub);
+}
+
+const NAME: &str = "LenPrefixed";
+const FIELDS: &[&str; 2] = &["len", "data"];
+
+impl<F, C> SerializeWith<C> for LenPrefixed<F>
+where
+    F: SerializeWith<C>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator,
+{
+    fn serialize_with<S: Serializer>(value: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = value.into_iter().count() as u64;
+        fields::serialize_pair(
+            serializer,
+            NAME,
+            FIELDS,
+            &len,
+            &WithEncoding::<&F, &C>::from(value),
+        )
+    }
+}
+
+impl<'de, F, C> DeserializeWith<'de, C> for LenPrefixed<F>
+where
+    F: DeserializeWith<'de, C>,
+    for<'a> &'a C: IntoIterator,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (len, data) =
+            fields::deserialize_pair::<_, u64, WithEncoding<F, C>>(deserializer, NAME, FIELDS)?;
+        let data = data.into_inner();
+        let count = (&data).into_iter().count();
+        if len == count as u64 {
+            Ok(data)
+        } else {
+            Err(D::Error::custom(format_args!(
+                "length prefix {len} does not match the {count} elements found"
+            )))
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::LenPrefixed::<sa::Seq<sa::Str>>")] Vec<i32>);
+
+    #[test]
+    fn length_is_serialized_with_data() {
+        check_serialization(Foo(vec![4, 5]), json!({ "len": 2, "data": ["4", "5"] }));
+        check_serialization(Foo(vec![]), json!({ "len": 0, "data": [] }));
+    }
+
+    #[test]
+    fn length_mismatch_is_rejected() {
+        let e = serde_json::from_value::<Foo>(json!({ "len": 3, "data": ["4", "5"] })).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "length prefix 3 does not match the 2 elements found"
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Packet(#[serde(with = "sa::LenPrefixed::<sa::Bytes>")] Vec<u8>);
+
+    #[test]
+    fn bytes_roundtrip_with_bincode() {
+        let original = Packet(vec![1, 2, 3]);
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Packet>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -42,7 +42,7 @@
 //!    composability.
 //! 1. Define `serialize` and `deserialize` inherent functions for this type, delegating to
 //!    [`SerializeWith`] and [`DeserializeWith`] respectively. These are the functions the
-//!    serde-generated code calls.
+//!    serde-generated code calls. The [`adapter_methods`] macro can define them.
 //!
 //! ## Simple adapter example
 //! ```
@@ -122,22 +122,7 @@
 //! struct Coords<F>(PhantomData<F>);
 //!
 //! impl<F> Coords<F> {
-//!     fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-//!     where
-//!         T: ?Sized,
-//!         S: Serializer,
-//!         Self: SerializeWith<T>,
-//!     {
-//!         Self::serialize_with(value, serializer)
-//!     }
-//!
-//!     fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
-//!     where
-//!         D: Deserializer<'de>,
-//!         Self: DeserializeWith<'de, T>,
-//!     {
-//!         Self::deserialize_with(deserializer)
-//!     }
+//!     serdapt::adapter_methods!();
 //! }
 //!
 //! impl<F, T> SerializeWith<Point<T>> for Coords<F>
@@ -194,8 +179,10 @@
 //! - It avoids a macro ordering issue that can lead to generated serialization code not using the
 //!   requested adapter despite a sucessful compilation.
 //! - It works seamlessly with conditional compilation.
-//! - It is limited to supporting types in the standard library, with support for third-party types
-//!   delegated to other crates, which solves dependency issues.
+//! - It focuses on types in the standard library. Adapters for a few third-party crates (`arrayvec`,
+//!   `base64`, `crc32fast`, `flate2`, `humantime`, `indexmap`, `log`, `ordered-float` and
+//!   `serde_json`) are available behind optional features that are disabled by default, so that
+//!   dependencies are only pulled in when requested.
 //!
 //! # Contribute
 //! All contributions shall be licensed under the [0BSD license](https://spdx.org/licenses/0BSD.html).
@@ -208,65 +195,283 @@ extern crate alloc;
 
 mod add_ref;
 mod array;
+mod atomic;
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "alloc")]
+mod bit_pack;
+mod bool_from_int;
+#[cfg(feature = "arrayvec")]
+mod bounded_array;
+mod bounded_seq;
 mod bytes;
+#[cfg(feature = "alloc")]
+mod case;
 mod cell;
+mod char;
+#[cfg(feature = "json")]
+mod checked;
+#[cfg(feature = "alloc")]
+mod chunks;
+mod clamp;
 mod codec;
+#[cfg(feature = "json")]
+mod compress;
+#[cfg(feature = "alloc")]
+mod content;
 mod convert;
+mod count;
 #[cfg(feature = "alloc")]
 mod cow;
+#[cfg(feature = "alloc")]
+mod default_on_error;
+mod default_on_null;
+#[cfg(feature = "alloc")]
+mod delimited;
+mod discriminant;
+mod double_option;
+mod endian;
+mod fields;
+mod flex_bool;
+mod float;
+mod fold;
 mod from;
+mod grouped;
+#[cfg(feature = "alloc")]
+mod heap;
+mod hex;
 mod human;
+#[cfg(feature = "humantime")]
+mod human_duration;
 mod identity;
+mod in_range;
+mod int_if_whole;
+mod int_parts;
 mod into;
+mod is_default;
+#[cfg(feature = "json")]
+mod json_string;
+#[cfg(feature = "alloc")]
+mod key_case;
+mod len_prefixed;
+mod macros;
 mod map;
 mod map_as_seq;
+#[cfg(feature = "alloc")]
+mod map_columns;
 #[cfg(feature = "std")]
 mod mutex;
+mod nested;
+#[cfg(any(feature = "std", serdapt_core_net))]
+mod net;
+mod nonzero;
+mod ok_option;
+mod once;
+#[cfg(feature = "alloc")]
+mod one_or_many;
 mod option;
+mod option_flat;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+#[cfg(feature = "alloc")]
+mod ordered_map;
+#[cfg(feature = "std")]
+mod os_str;
+#[cfg(feature = "std")]
+mod path;
+#[cfg(feature = "alloc")]
+mod pick_first;
 mod ptr;
+mod radix;
 mod range;
+mod reduce;
+mod ref_into;
+mod ref_try_into;
 mod result;
+mod rev_seq;
 mod reverse;
+#[cfg(feature = "humantime")]
+mod rfc3339;
 #[cfg(feature = "std")]
 mod rwlock;
+mod safe_int;
+mod saturating;
+mod saturating_into;
+mod scale;
 mod seq_as_map;
+mod seq_skip_none;
 mod sequence;
+#[cfg(feature = "alloc")]
+mod set;
+mod sign_magnitude;
+mod signedness;
+mod size_hint;
+#[cfg(feature = "alloc")]
+mod sorted_entries;
 mod str;
+mod str_len;
+mod tagged;
+mod tap;
+#[cfg(feature = "alloc")]
+mod text;
+mod then;
+mod transparent;
+mod trimmed;
 mod try_from;
 mod try_into;
+mod tuple_as_struct;
+mod unit_struct;
+mod utf8;
+mod variants;
 mod wrapping;
+mod zigzag;
 
 pub use add_ref::AddRef;
 pub use array::Array;
+pub use atomic::{Atomic, AtomicAcquire, AtomicRelaxed, AtomicSeqCst, LoadOrdering};
+#[cfg(feature = "base64")]
+pub use base64::{
+    Base64, Base64Config, Base64Standard, Base64StandardNoPad, Base64UrlSafe, Base64UrlSafeNoPad,
+};
+#[cfg(feature = "alloc")]
+pub use bit_pack::BitPack;
+pub use bool_from_int::{
+    BoolFromInt, BoolFromIntEncoding, BoolFromIntMode, LenientBoolFromInt, LenientBoolInt,
+    StrictBoolInt,
+};
+#[cfg(feature = "arrayvec")]
+pub use bounded_array::BoundedArray;
+pub use bounded_seq::BoundedSeq;
 #[cfg(feature = "alloc")]
 pub use bytes::ByteVec;
 pub use bytes::Bytes;
+pub use bytes::{ByteSeq, FixedBytes};
+#[cfg(feature = "alloc")]
+pub use case::{AsciiCase, CaseFolding, Lowercase, UnicodeCase, Uppercase};
 pub use cell::Cell;
+pub use char::{AllowCodePoint, Char, CharEncoding, CharMode, CharOrCodePoint, TextOnly};
+#[cfg(feature = "crc32")]
+pub use checked::Crc32;
+#[cfg(feature = "json")]
+pub use checked::{Checked, Checksum};
+#[cfg(feature = "alloc")]
+pub use chunks::Chunks;
+pub use clamp::Clamp;
 pub use codec::Codec;
-pub use convert::{Convert, RefConvert, RefTryConvert, TryConvert};
+#[cfg(feature = "gzip")]
+pub use compress::Gzip;
+#[cfg(feature = "json")]
+pub use compress::{CompressOver, Compression};
+pub use convert::{AsTuple, Convert, RefConvert, RefTryConvert, TryConvert};
+pub use count::Count;
+#[cfg(feature = "alloc")]
+pub use cow::{BorrowCow, Cow};
 #[cfg(feature = "alloc")]
-pub use cow::Cow;
+pub use default_on_error::DefaultOnError;
+pub use default_on_null::DefaultOnNull;
+#[cfg(feature = "alloc")]
+pub use delimited::Delimited;
+pub use discriminant::Discriminant;
+pub use double_option::DoubleOption;
+pub use endian::{BeBytes, LeBytes};
+pub use flex_bool::{BoolTokens, CommonBoolTokens, FlexBool, FlexBoolWith};
+pub use float::{Float, FloatMode, NumberWhenFinite, StringAlways};
+pub use fold::{Fold, FoldOp, FoldWith, SumOp, SumOverflow, Summand};
 pub use from::From;
+pub use grouped::{Grouped, GroupedBy};
+#[cfg(feature = "alloc")]
+pub use heap::Heap;
+pub use hex::{Hex, HexEncoding, SmartBytes, UpperHex};
 pub use human::HumanOr;
+#[cfg(feature = "humantime")]
+pub use human_duration::HumanDuration;
 pub use identity::Id;
+pub use in_range::{InRange, RangeBound};
+pub use int_if_whole::IntIfWhole;
 pub use into::Into;
-pub use map::Map;
+pub use is_default::is_default;
+#[cfg(feature = "json")]
+pub use json_string::JsonString;
+#[cfg(feature = "alloc")]
+pub use key_case::{CamelCase, CaseStyle, KebabCase, KeyCase, SnakeCase};
+pub use len_prefixed::LenPrefixed;
+#[cfg(feature = "alloc")]
+pub use map::StrictMap;
+pub use map::{Flatten, Map};
+#[cfg(feature = "std")]
+pub use map::{MakeHasher, MapWithHasher};
 pub use map_as_seq::MapAsSeq;
+#[cfg(feature = "alloc")]
+pub use map_columns::MapColumns;
 #[cfg(feature = "std")]
-pub use mutex::Mutex;
+pub use mutex::{BlockingLock, LockMode, Mutex, MutexRecover, NonBlockingLock};
+pub use nested::Nested;
+#[cfg(any(feature = "std", serdapt_core_net))]
+pub use net::{
+    AddrOctets, AddrText, Ip, IpEncoding, IpOctets, IpPort, Socket, SocketEncoding, SocketStruct,
+};
+pub use nonzero::NonZero;
+pub use ok_option::OkOption;
+pub use once::Once;
+#[cfg(feature = "alloc")]
+pub use one_or_many::{OneOrMany, OneOrManyMode, PreferMany, PreferOne};
 pub use option::Option;
+pub use option_flat::OptionFlat;
+#[cfg(feature = "ordered-float")]
+pub use ordered_float::OrderedFloat;
+#[cfg(feature = "alloc")]
+pub use ordered_map::{EntryOrder, KeysDescending, OrderedMap, ValuesAscending};
+#[cfg(feature = "std")]
+pub use os_str::{OsStr, OsStrEncoding};
+#[cfg(all(feature = "std", unix))]
+pub use os_str::{OsStrBytes, RawBytes};
+#[cfg(feature = "std")]
+pub use path::{LossyPathUtf8, LossyUtf8, PathEncoding, PathUtf8, StrictUtf8, Utf8Mode};
+#[cfg(feature = "alloc")]
+pub use pick_first::PickFirst;
 pub use ptr::Ptr;
+pub use radix::Radix;
 pub use range::Range;
-pub use result::Result;
+pub use reduce::{MaxOp, MinOp, Reduce, ReduceOp, ReduceWith};
+pub use ref_into::RefInto;
+pub use ref_try_into::RefTryInto;
+pub use result::{NamedResult, Result, ResultVariants};
+pub use rev_seq::{RevIntoIter, RevSeq};
 pub use reverse::Reverse;
+#[cfg(feature = "humantime")]
+pub use rfc3339::Rfc3339;
 #[cfg(feature = "std")]
 pub use rwlock::RwLock;
+pub use safe_int::{JsSafe, SafeInt, SafeIntEncoding, SafeIntLimit};
+pub use saturating::Saturating;
+pub use saturating_into::{SaturatingFrom, SaturatingInto};
+pub use scale::Scale;
 pub use seq_as_map::SeqAsMap;
+pub use seq_skip_none::SeqSkipNone;
 pub use sequence::Seq;
+#[cfg(feature = "alloc")]
+pub use set::Set;
+pub use sign_magnitude::SignMagnitude;
+pub use signedness::{AsSigned, AsUnsigned};
+#[cfg(feature = "alloc")]
+pub use sorted_entries::SortedEntries;
 pub use str::Str;
+pub use str_len::StrLen;
+pub use tagged::{AdjacentTag, ExternalTag, TagFields, TagMode, TagPayload, Tagged, TypeValue};
+#[cfg(feature = "log")]
+pub use tap::LogHook;
+pub use tap::{Tap, TapHook};
+pub use then::Then;
+pub use transparent::{Newtype, Transparent};
+pub use trimmed::{AsciiWhitespace, CharSet, Trimmed, Whitespace};
 pub use try_from::TryFrom;
 pub use try_into::TryInto;
+pub use tuple_as_struct::{StructFields, TupleAsStruct};
+pub use unit_struct::{StructName, UnitStruct};
+pub use utf8::Utf8;
+pub use variants::{VariantAdapters, VariantDeserializer, VariantSerializer, Variants};
 pub use wrapping::Wrapping;
+pub use zigzag::ZigZag;
 
 use core::marker::PhantomData;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -333,6 +538,70 @@ impl<F, T> WithEncoding<F, T> {
     }
 }
 
+impl<F, T> WithEncoding<F, T>
+where
+    T: ?Sized,
+{
+    /// Returns a reference to the inner value
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the inner value
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<F, T> Clone for WithEncoding<F, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.value.clone().into()
+    }
+}
+
+impl<F, T> Copy for WithEncoding<F, T> where T: Copy {}
+
+impl<F, T> core::fmt::Debug for WithEncoding<F, T>
+where
+    T: core::fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<F, T> PartialEq for WithEncoding<F, T>
+where
+    T: PartialEq + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F, T> Eq for WithEncoding<F, T> where T: Eq + ?Sized {}
+
+impl<F, T> core::hash::Hash for WithEncoding<F, T>
+where
+    T: core::hash::Hash + ?Sized,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<F, T> Default for WithEncoding<F, T>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        T::default().into()
+    }
+}
+
 impl<F, T> core::convert::From<T> for WithEncoding<F, T> {
     fn from(value: T) -> Self {
         Self {
@@ -400,25 +669,94 @@ macro_rules! impl_tuple {
     };
 }
 
+/// Implements the adapter traits for tuples too large for serde's own tuple implementations
+macro_rules! impl_large_tuple {
+    ($($types:ident $adapters:ident $xs:ident,)*) => {
+        impl<$($types, $adapters),*> SerializeWith<($($types,)*)> for ($($adapters,)*)
+        where
+            $($adapters: SerializeWith<$types>,)*
+        {
+            fn serialize_with<S: Serializer>(
+                value: &($($types,)*),
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple as _;
+
+                let ($($xs,)*) = value;
+                let mut out = serializer.serialize_tuple([$(stringify!($xs)),*].len())?;
+                $(out.serialize_element(&WithEncoding::<&$adapters, _>::from($xs))?;)*
+                out.end()
+            }
+        }
+
+        impl<'de, $($types, $adapters),*> DeserializeWith<'de, ($($types,)*)> for ($($adapters,)*)
+        where
+            $($adapters: DeserializeWith<'de, $types>,)*
+        {
+            fn deserialize_with<D>(deserializer: D) -> core::result::Result<($($types,)*), D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_tuple(
+                    [$(stringify!($xs)),*].len(),
+                    LargeTupleVisitor::<($($adapters,)*), ($($types,)*)>(PhantomData),
+                )
+            }
+        }
+
+        impl<'de, $($types, $adapters),*> serde::de::Visitor<'de>
+            for LargeTupleVisitor<($($adapters,)*), ($($types,)*)>
+        where
+            $($adapters: DeserializeWith<'de, $types>,)*
+        {
+            type Value = ($($types,)*);
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a tuple of size {}", [$(stringify!($xs)),*].len())
+            }
+
+            #[allow(unused_assignments)]
+            fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut len = 0;
+                $(
+                    let $xs = seq
+                        .next_element::<WithEncoding<$adapters, $types>>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(len, &self))?
+                        .value;
+                    len += 1;
+                )*
+                Ok(($($xs,)*))
+            }
+        }
+    };
+}
+
+struct LargeTupleVisitor<A, T>(PhantomData<fn() -> (A, T)>);
+
 macro_rules! impl_tuples {
     (
+        $imp:ident;
         $($tys:ident $adapters:ident $xs:ident,)*
         @ $ty_head:ident $adapter_head:ident $x_head:ident, $($ty_tail:ident $adapter_tail:ident $x_tail:ident,)*
     ) => {
-        impl_tuple!($($tys $adapters $xs,)*);
-        impl_tuples!($($tys $adapters $xs,)* $ty_head $adapter_head $x_head, @ $($ty_tail $adapter_tail $x_tail,)*);
+        $imp!($($tys $adapters $xs,)*);
+        impl_tuples!($imp; $($tys $adapters $xs,)* $ty_head $adapter_head $x_head, @ $($ty_tail $adapter_tail $x_tail,)*);
 
     };
-    ($($tys:ident $adapters:ident $xs:ident,)* @) => {
-        impl_tuple!($($tys $adapters $xs,)*);
+    ($imp:ident; $($tys:ident $adapters:ident $xs:ident,)* @) => {
+        $imp!($($tys $adapters $xs,)*);
 
     };
-    ($($tys:ident $adapters:ident $xs:ident,)*) => {
-        impl_tuples!(@ $($tys $adapters $xs,)*);
+    ($imp:ident; $($tys:ident $adapters:ident $xs:ident,)*) => {
+        impl_tuples!($imp; @ $($tys $adapters $xs,)*);
     };
 }
 
 impl_tuples!(
+    impl_tuple;
     T0 A0 x0,
     T1 A1 x1,
     T2 A2 x2,
@@ -437,6 +775,40 @@ impl_tuples!(
     T15 A15 x15,
 );
 
+impl_tuples!(
+    impl_large_tuple;
+    T0 A0 x0,
+    T1 A1 x1,
+    T2 A2 x2,
+    T3 A3 x3,
+    T4 A4 x4,
+    T5 A5 x5,
+    T6 A6 x6,
+    T7 A7 x7,
+    T8 A8 x8,
+    T9 A9 x9,
+    T10 A10 x10,
+    T11 A11 x11,
+    T12 A12 x12,
+    T13 A13 x13,
+    T14 A14 x14,
+    T15 A15 x15,
+    T16 A16 x16,
+    @
+    T17 A17 x17,
+    T18 A18 x18,
+    T19 A19 x19,
+    T20 A20 x20,
+    T21 A21 x21,
+    T22 A22 x22,
+    T23 A23 x23,
+);
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
+
 #[cfg(test)]
 mod test_utils {
     use core::fmt::Debug;
@@ -454,3 +826,112 @@ mod test_utils {
         assert_eq!(x, deserialized);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, WithEncoding};
+    use serde_json::json;
+
+    type Adapters = (
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+        sa::Str,
+        sa::Id,
+    );
+
+    type Values = (
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+        u8,
+    );
+
+    const VALUES: Values = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17);
+
+    fn expected() -> serde_json::Value {
+        json!(["0", 1, "2", 3, "4", 5, "6", 7, "8", 9, "10", 11, "12", 13, "14", 15, "16", 17])
+    }
+
+    #[test]
+    fn large_tuple_adapter_roundtrips() {
+        let serialized = serde_json::to_value(WithEncoding::<Adapters, _>::from(VALUES)).unwrap();
+        assert_eq!(serialized, expected());
+        let deserialized =
+            serde_json::from_value::<WithEncoding<Adapters, Values>>(serialized).unwrap();
+        assert_eq!(serde_json::to_value(deserialized).unwrap(), expected());
+    }
+
+    #[test]
+    fn large_tuple_adapter_roundtrips_with_bincode() {
+        let serialized = bincode::serialize(&WithEncoding::<Adapters, _>::from(VALUES)).unwrap();
+        let deserialized =
+            bincode::deserialize::<WithEncoding<Adapters, Values>>(&serialized).unwrap();
+        assert_eq!(serde_json::to_value(deserialized).unwrap(), expected());
+    }
+
+    #[test]
+    fn short_large_tuple_fails() {
+        let result = serde_json::from_value::<WithEncoding<Adapters, Values>>(json!(["0", 1]));
+        assert!(result.is_err());
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    struct Point {
+        x: WithEncoding<sa::Str, i32>,
+        y: WithEncoding<sa::Str, i32>,
+    }
+
+    #[test]
+    fn with_encoding_delegates_to_inner_value() {
+        let p = Point {
+            x: 1.into(),
+            y: 2.into(),
+        };
+        let q = p;
+        assert_eq!(p.clone(), q);
+        assert_ne!(
+            p,
+            Point {
+                x: 1.into(),
+                y: 3.into()
+            }
+        );
+        assert_eq!(format!("{:?}", p.x), "1");
+    }
+
+    #[test]
+    fn with_encoding_gives_access_to_inner_value() {
+        let mut x = WithEncoding::<sa::Str, i32>::from(1);
+        assert_eq!(*x.get(), 1);
+        *x.get_mut() += 1;
+        assert_eq!(x.into_inner(), 2);
+    }
+}
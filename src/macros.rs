@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+/// Defines the `serialize` and `deserialize` inherent functions of an adapter
+///
+/// This macro is meant to be invoked in an inherent `impl` block of an adapter type. It expands to
+/// `serialize` and `deserialize` functions delegating to [`SerializeWith`](crate::SerializeWith)
+/// and [`DeserializeWith`](crate::DeserializeWith) respectively. An optional visibility applies to
+/// both functions.
+///
+/// The generated functions declare generic parameters named `T`, `S` and `D`, so the `impl` block
+/// must not use these names for its own parameters.
+///
+/// # Example
+/// ```
+/// use core::marker::PhantomData;
+/// use serdapt::{DeserializeWith, SerializeWith};
+/// use serde::{Deserializer, Serializer};
+/// use serde_json::json;
+///
+/// struct Twice<F>(PhantomData<F>);
+///
+/// impl<F> Twice<F> {
+///     serdapt::adapter_methods!(pub);
+/// }
+///
+/// impl<F, T> SerializeWith<T> for Twice<F>
+/// where
+///     F: SerializeWith<T>,
+/// {
+///     fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+///         let value = serdapt::WithEncoding::<&F, _>::from(value);
+///         serde::Serialize::serialize(&(&value, &value), serializer)
+///     }
+/// }
+///
+/// impl<'de, F, T> DeserializeWith<'de, T> for Twice<F>
+/// where
+///     F: DeserializeWith<'de, T>,
+/// {
+///     fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+///     where
+///         D: Deserializer<'de>,
+///     {
+///         let (x, _): (serdapt::WithEncoding<F, T>, serde::de::IgnoredAny) =
+///             serde::Deserialize::deserialize(deserializer)?;
+///         Ok(x.into_inner())
+///     }
+/// }
+///
+/// #[derive(Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+/// struct Foo(#[serde(with = "Twice::<serdapt::Str>")] i32);
+///
+/// let serialized = serde_json::to_value(Foo(33)).unwrap();
+/// assert_eq!(serialized, json!(["33", "33"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, Foo(33));
+/// ```
+#[macro_export]
+macro_rules! adapter_methods {
+    () => {
+        $crate::adapter_methods!(pub(self));
+    };
+    ($vis:vis) => {
+        /// Serializes value with adapter
+        $vis fn serialize<T, S>(
+            value: &T,
+            serializer: S,
+        ) -> ::core::result::Result<S::Ok, S::Error>
+        where
+            T: ?Sized,
+            S: $crate::__private::serde::Serializer,
+            Self: $crate::SerializeWith<T>,
+        {
+            <Self as $crate::SerializeWith<T>>::serialize_with(value, serializer)
+        }
+
+        /// Deserializes value with adapter
+        $vis fn deserialize<'de, T, D>(deserializer: D) -> ::core::result::Result<T, D::Error>
+        where
+            D: $crate::__private::serde::Deserializer<'de>,
+            Self: $crate::DeserializeWith<'de, T>,
+        {
+            <Self as $crate::DeserializeWith<'de, T>>::deserialize_with(deserializer)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::check_serialization, DeserializeWith, SerializeWith};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::json;
+
+    struct Negate;
+
+    impl Negate {
+        adapter_methods!();
+    }
+
+    impl SerializeWith<i32> for Negate {
+        fn serialize_with<S: Serializer>(value: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+            Serialize::serialize(&-value, serializer)
+        }
+    }
+
+    impl<'de> DeserializeWith<'de, i32> for Negate {
+        fn deserialize_with<D>(deserializer: D) -> Result<i32, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            i32::deserialize(deserializer).map(|x| -x)
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "Negate")] i32);
+
+    #[test]
+    fn generated_methods_work_for_unit_struct() {
+        check_serialization(Foo(3), json!(-3));
+    }
+}
@@ -1,17 +1,37 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
-use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use crate::{size_hint, DeserializeWith, SerializeWith, WithEncoding};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
 use core::{fmt, marker::PhantomData};
+#[cfg(feature = "alloc")]
+use serde::de::Error as _;
 use serde::{
     de::{MapAccess, Visitor},
     Deserializer, Serializer,
 };
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Map adapter to customize how keys and values are serialized
 ///
 /// This adapter causes a map to be serialized such that its keys are serialized with `F` and its
 /// values are serialized with `G`.
 ///
+/// Entries are serialized in iteration order and inserted in input order when deserializing, so
+/// maps preserving insertion order, such as `IndexMap` from the `indexmap` crate, round-trip with
+/// their order intact. Support for `indexmap` types is enabled by the `indexmap` feature.
+///
+/// Serializing a map `C` requires `&C` to implement [`IntoIterator`] over pairs of references to
+/// keys and values, and deserializing requires `C` to implement [`IntoIterator`] and
+/// [`FromIterator`] over pairs of keys and values. A newtype wrapping a map can be handled with
+/// [`Transparent<Map<F, G>>`](crate::Transparent) instead of delegating these traits.
+///
+/// Deserializing a `HashMap` requires its hash builder to implement [`Default`]. See
+/// `MapWithHasher` for other hash builders, which requires the `std` feature.
+///
 /// # Example
 /// ```
 /// # #[cfg(feature = "std")] {
@@ -82,6 +102,255 @@ where
     }
 }
 
+/// Adapter for a map whose entries are spread into the parent struct, with values serialized with
+/// `F`
+///
+/// The field must also be annotated with `#[serde(flatten)]`. serde then serializes the map
+/// entries as fields of the parent struct, and collects the fields not matching other struct
+/// fields into the map when deserializing. Keys are serialized as they are, and values are
+/// serialized with `F`.
+///
+/// The limitations of `#[serde(flatten)]` apply:
+/// - Keys become field names, so they should be strings, or types serialized as strings.
+/// - The parent struct is serialized as a map and deserializing it requires a self-describing
+///   format, which rules out formats like bincode.
+/// - All unknown fields of the parent struct end up in the map, and their values must be
+///   deserializable with `F`. `#[serde(deny_unknown_fields)]` cannot be used on the parent.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     id: u32,
+///     #[serde(flatten, with = "sa::Flatten::<sa::Str>")]
+///     extra: BTreeMap<String, i32>,
+/// }
+///
+/// let foo = Foo { id: 1, extra: BTreeMap::from_iter([("x".to_owned(), 33)]) };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "id": 1, "x": "33" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub type Flatten<F = crate::Id> = Map<crate::Id, F>;
+
+/// Map adapter rejecting duplicate keys
+///
+/// This adapter behaves like [`Map`], except that deserialization fails if the map contains
+/// duplicate keys. Entries are buffered and their deserialized keys compared before being collected
+/// into the container, so duplicates are detected whatever the container does with them. This
+/// requires keys to implement [`Ord`].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "sa::StrictMap::<sa::Str, sa::Id>")] HashMap<i32, i32>);
+///
+/// let foo = serde_json::from_str::<Foo>(r#"{ "1": 2, "3": 4 }"#).unwrap();
+/// assert_eq!(foo.0, HashMap::from_iter([(1, 2), (3, 4)]));
+/// assert!(serde_json::from_str::<Foo>(r#"{ "1": 2, "1": 4 }"#).is_err());
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub struct StrictMap<F, G>(PhantomData<(F, G)>);
+
+#[cfg(feature = "alloc")]
+impl<F, G> StrictMap<F, G> {
+    crate::adapter_methods!(pub);
+}
+
+#[cfg(feature = "alloc")]
+impl<F, G, C> SerializeWith<C> for StrictMap<F, G>
+where
+    Map<F, G>: SerializeWith<C>,
+    C: ?Sized,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        Map::<F, G>::serialize_with(container, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, F, G, C, K, V> DeserializeWith<'de, C> for StrictMap<F, G>
+where
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    C: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+    K: Ord,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries =
+            <Map<F, G> as DeserializeWith<'de, Vec<(K, V)>>>::deserialize_with(deserializer)?;
+        if has_duplicate_keys(entries.iter().map(|(k, _)| k)) {
+            Err(D::Error::custom("duplicate map key"))
+        } else {
+            Ok(entries.into_iter().collect())
+        }
+    }
+}
+
+/// Returns whether `keys` contains duplicates
+#[cfg(feature = "alloc")]
+pub(crate) fn has_duplicate_keys<'a, K, I>(keys: I) -> bool
+where
+    K: Ord + 'a,
+    I: IntoIterator<Item = &'a K>,
+{
+    let mut keys = keys.into_iter().collect::<Vec<_>>();
+    keys.sort_unstable();
+    keys.windows(2).any(|w| w[0] == w[1])
+}
+
+/// Map adapter to deserialize hash maps whose hash builder is created by `H`
+///
+/// [`Map`] can only deserialize a [`HashMap`](std::collections::HashMap) if its hash builder
+/// implements [`Default`]. This adapter behaves like [`Map`] but creates the hash builder with
+/// [`MakeHasher::make_hasher`] instead, which supports hash builders that need to be seeded or
+/// configured.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::{
+///     collections::{hash_map::DefaultHasher, HashMap},
+///     hash::{BuildHasher, Hasher},
+/// };
+///
+/// struct Seeded(u64);
+///
+/// impl BuildHasher for Seeded {
+///     type Hasher = DefaultHasher;
+///
+///     fn build_hasher(&self) -> DefaultHasher {
+///         let mut hasher = DefaultHasher::new();
+///         hasher.write_u64(self.0);
+///         hasher
+///     }
+/// }
+///
+/// struct Seed;
+///
+/// impl sa::MakeHasher for Seed {
+///     type Hasher = Seeded;
+///
+///     fn make_hasher() -> Seeded {
+///         Seeded(33)
+///     }
+/// }
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(
+///     #[serde(with = "sa::MapWithHasher::<Seed, sa::Str, sa::Id>")] HashMap<i32, i32, Seeded>,
+/// );
+///
+/// let foo = serde_json::from_value::<Foo>(json!({ "1": 2 })).unwrap();
+/// assert_eq!(foo.0.get(&1), Some(&2));
+/// ```
+#[cfg(feature = "std")]
+pub struct MapWithHasher<H, F, G>(PhantomData<(H, F, G)>);
+
+#[cfg(feature = "std")]
+impl<H, F, G> MapWithHasher<H, F, G> {
+    crate::adapter_methods!(pub);
+}
+
+/// Source of hash builders used by [`MapWithHasher`]
+#[cfg(feature = "std")]
+pub trait MakeHasher {
+    /// Type of the hash builder
+    type Hasher: BuildHasher;
+
+    /// Creates a hash builder
+    fn make_hasher() -> Self::Hasher;
+}
+
+#[cfg(feature = "std")]
+impl<H, F, G, C> SerializeWith<C> for MapWithHasher<H, F, G>
+where
+    Map<F, G>: SerializeWith<C>,
+    C: ?Sized,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        Map::<F, G>::serialize_with(container, serializer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, H, F, G, K, V> DeserializeWith<'de, HashMap<K, V, H::Hasher>> for MapWithHasher<H, F, G>
+where
+    H: MakeHasher,
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    K: Eq + Hash,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<HashMap<K, V, H::Hasher>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(HashMapVisitor::<H, F, G, K, V>::new())
+    }
+}
+
+#[cfg(feature = "std")]
+struct HashMapVisitor<H, F, G, K, V> {
+    _adapter: PhantomData<(H, F, G)>,
+    _map: PhantomData<fn() -> (K, V)>,
+}
+
+#[cfg(feature = "std")]
+impl<H, F, G, K, V> HashMapVisitor<H, F, G, K, V> {
+    fn new() -> Self {
+        HashMapVisitor {
+            _adapter: PhantomData,
+            _map: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, H, F, G, K, V> Visitor<'de> for HashMapVisitor<H, F, G, K, V>
+where
+    H: MakeHasher,
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    K: Eq + Hash,
+{
+    type Value = HashMap<K, V, H::Hasher>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let capacity = size_hint::capacity::<(K, V)>(map.size_hint());
+        let mut out = HashMap::with_capacity_and_hasher(capacity, H::make_hasher());
+        while let Some((k, v)) = map.next_entry::<WithEncoding<F, K>, WithEncoding<G, V>>()? {
+            out.insert(k.into_inner(), v.into_inner());
+        }
+        Ok(out)
+    }
+}
+
 struct MapVisitor<F, G, C> {
     _f: PhantomData<(F, G)>,
     _c: PhantomData<fn() -> C>,
@@ -112,12 +381,13 @@ where
     where
         A: MapAccess<'de>,
     {
-        core::iter::from_fn(|| {
+        let hint = map.size_hint();
+        let entries = core::iter::from_fn(|| {
             map.next_entry::<WithEncoding<F, K>, WithEncoding<G, V>>()
                 .map(|x| x.map(|(k, v)| (k.into_inner(), v.into_inner())))
                 .transpose()
-        })
-        .collect()
+        });
+        size_hint::collect(hint, entries)
     }
 }
 
@@ -126,7 +396,10 @@ mod tests {
     use crate::{self as sa, test_utils::check_serialization};
     use serde::{Deserialize, Serialize};
     use serde_json::json;
-    use std::collections::{BTreeMap, HashMap};
+    use std::{
+        collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+        hash::{BuildHasher, Hasher},
+    };
 
     #[derive(Debug, Deserialize, PartialEq, Serialize)]
     struct WrapHashMap(
@@ -153,4 +426,130 @@ mod tests {
             json!({ "33": ["0", "1"], "34": ["0", "2"] }),
         );
     }
+
+    #[test]
+    fn invalid_map_value_fails() {
+        serde_json::from_value::<WrapBTreeMap>(json!({ "33": ["0", "1"], "34": ["0"] }))
+            .unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Flat {
+        id: u32,
+        #[serde(flatten, with = "sa::Flatten::<sa::Str>")]
+        extra: BTreeMap<String, i32>,
+    }
+
+    #[test]
+    fn flattened_entries_are_spread_into_parent() {
+        check_serialization(
+            Flat {
+                id: 1,
+                extra: BTreeMap::from_iter([("x".to_owned(), 3), ("y".to_owned(), -4)]),
+            },
+            json!({ "id": 1, "x": "3", "y": "-4" }),
+        );
+        check_serialization(
+            Flat {
+                id: 1,
+                extra: BTreeMap::new(),
+            },
+            json!({ "id": 1 }),
+        );
+    }
+
+    #[test]
+    fn flattened_unknown_field_with_invalid_value_fails() {
+        serde_json::from_value::<Flat>(json!({ "id": 1, "x": true })).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Strict(#[serde(with = "sa::StrictMap::<sa::Str, sa::Id>")] BTreeMap<i32, i32>);
+
+    #[test]
+    fn strict_map_roundtrips() {
+        check_serialization(
+            Strict(BTreeMap::from_iter([(1, 2), (3, 4)])),
+            json!({ "1": 2, "3": 4 }),
+        );
+    }
+
+    #[test]
+    fn strict_map_rejects_duplicate_keys() {
+        serde_json::from_str::<Strict>(r#"{ "1": 2, "3": 4, "1": 5 }"#).unwrap_err();
+    }
+
+    #[test]
+    fn strict_map_rejects_keys_colliding_after_deserialization() {
+        serde_json::from_str::<Strict>(r#"{ "1": 2, "01": 3 }"#).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct StrictEntries(#[serde(with = "sa::StrictMap::<sa::Str, sa::Id>")] Vec<(u8, u8)>);
+
+    #[test]
+    fn strict_map_rejects_duplicate_keys_kept_by_container() {
+        serde_json::from_str::<StrictEntries>(r#"{ "1": 2, "1": 3 }"#).unwrap_err();
+        let v = serde_json::from_str::<StrictEntries>(r#"{ "1": 2, "3": 4 }"#).unwrap();
+        assert_eq!(v, StrictEntries(vec![(1, 2), (3, 4)]));
+    }
+
+    struct Seeded(u64);
+
+    impl BuildHasher for Seeded {
+        type Hasher = DefaultHasher;
+
+        fn build_hasher(&self) -> DefaultHasher {
+            let mut hasher = DefaultHasher::new();
+            hasher.write_u64(self.0);
+            hasher
+        }
+    }
+
+    struct Seed;
+
+    impl sa::MakeHasher for Seed {
+        type Hasher = Seeded;
+
+        fn make_hasher() -> Seeded {
+            Seeded(33)
+        }
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct WithHasher(
+        #[serde(with = "sa::MapWithHasher::<Seed, sa::Str, sa::Id>")] HashMap<i32, i32, Seeded>,
+    );
+
+    #[test]
+    fn map_with_hasher_roundtrips() {
+        let mut map = HashMap::with_hasher(Seeded(33));
+        map.extend([(1, 2), (3, 4)]);
+        let serialized = serde_json::to_value(WithHasher(map)).unwrap();
+        assert_eq!(serialized, json!({ "1": 2, "3": 4 }));
+        let deserialized = serde_json::from_value::<WithHasher>(serialized).unwrap();
+        assert_eq!(deserialized.0.hasher().0, 33);
+        assert_eq!(deserialized.0.len(), 2);
+        assert_eq!(deserialized.0.get(&1), Some(&2));
+        assert_eq!(deserialized.0.get(&3), Some(&4));
+    }
+
+    #[test]
+    fn map_with_hasher_fails_on_invalid_key() {
+        serde_json::from_value::<WithHasher>(json!({ "x": 2 })).unwrap_err();
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct WrapIndexMap(#[serde(with = "sa::Map::<sa::Str, sa::Id>")] indexmap::IndexMap<i32, i32>);
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn map_adapter_preserves_index_map_order() {
+        let original = WrapIndexMap(indexmap::IndexMap::from_iter([(3, 4), (1, 2), (2, 3)]));
+        let serialized = serde_json::to_string(&original).unwrap();
+        assert_eq!(serialized, r#"{"3":4,"1":2,"2":3}"#);
+        let deserialized = serde_json::from_str::<WrapIndexMap>(&serialized).unwrap();
+        assert!(deserialized.0.iter().eq(original.0.iter()));
+    }
 }
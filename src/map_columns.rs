@@ -0,0 +1,151 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{
+    fields, map::has_duplicate_keys, DeserializeWith, Id, Seq, SerializeWith, WithEncoding,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize a map as two parallel sequences of keys and values
+///
+/// The map is serialized as a struct with fields `keys` and `values`, as some columnar formats
+/// expect. `F` is used to serialize keys and `G` is used to serialize values. When deserializing,
+/// keys and values are zipped back into entries. Deserialization fails if the sequences have
+/// different lengths or if there are duplicate keys. Deserialized keys are compared before entries
+/// are collected into the container, like [`StrictMap`](crate::StrictMap) does, so duplicates are
+/// detected whatever the container does with them. This requires keys to implement [`Ord`].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::MapColumns::<sa::Id, sa::Str>")] BTreeMap<String, i32>);
+///
+/// let foo = Foo(BTreeMap::from_iter([("a".into(), 1), ("b".into(), 2)]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "keys": ["a", "b"], "values": ["1", "2"] }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct MapColumns<F = Id, G = Id>(PhantomData<(F, G)>);
+
+impl<F, G> MapColumns<F, G> {
+    crate::adapter_methods!(pub);
+}
+
+const NAME: &str = "MapColumns";
+const FIELDS: &[&str; 2] = &["keys", "values"];
+
+impl<F, G, C, K, V> SerializeWith<C> for MapColumns<F, G>
+where
+    F: SerializeWith<K>,
+    G: SerializeWith<V>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let (keys, values): (Vec<_>, Vec<_>) = container.into_iter().unzip();
+        fields::serialize_pair(
+            serializer,
+            NAME,
+            FIELDS,
+            &WithEncoding::<Seq<&F>, _>::from(keys),
+            &WithEncoding::<Seq<&G>, _>::from(values),
+        )
+    }
+}
+
+impl<'de, F, G, C, K, V> DeserializeWith<'de, C> for MapColumns<F, G>
+where
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    C: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+    K: Ord,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (keys, values) = fields::deserialize_pair::<
+            _,
+            WithEncoding<Seq<F>, Vec<K>>,
+            WithEncoding<Seq<G>, Vec<V>>,
+        >(deserializer, NAME, FIELDS)?;
+        let (keys, values) = (keys.into_inner(), values.into_inner());
+        if keys.len() != values.len() {
+            return Err(D::Error::custom(format_args!(
+                "map has {} keys but {} values",
+                keys.len(),
+                values.len()
+            )));
+        }
+        if has_duplicate_keys(&keys) {
+            return Err(D::Error::custom("duplicate map key"));
+        }
+        Ok(keys.into_iter().zip(values).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{collections::BTreeMap, vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::MapColumns::<sa::Str, sa::Id>")] BTreeMap<u32, char>);
+
+    #[test]
+    fn map_roundtrips_as_columns() {
+        check_serialization(
+            Foo(BTreeMap::from_iter([(1, 'a'), (2, 'b')])),
+            json!({ "keys": ["1", "2"], "values": ['a', 'b'] }),
+        );
+        check_serialization(Foo(BTreeMap::new()), json!({ "keys": [], "values": [] }));
+    }
+
+    #[test]
+    fn map_columns_roundtrip_with_bincode() {
+        let original = Foo(BTreeMap::from_iter([(3, 'c'), (4, 'd')]));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn length_mismatch_is_rejected() {
+        let e = serde_json::from_value::<Foo>(json!({ "keys": ["1", "2"], "values": ['a'] }))
+            .unwrap_err();
+        assert_eq!(e.to_string(), "map has 2 keys but 1 values");
+    }
+
+    #[test]
+    fn duplicate_keys_are_rejected() {
+        let e = serde_json::from_value::<Foo>(json!({ "keys": ["1", "1"], "values": ['a', 'b'] }))
+            .unwrap_err();
+        assert_eq!(e.to_string(), "duplicate map key");
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Entries(#[serde(with = "sa::MapColumns::<sa::Str, sa::Id>")] Vec<(u32, char)>);
+
+    #[test]
+    fn duplicate_keys_kept_by_container_are_rejected() {
+        let e =
+            serde_json::from_value::<Entries>(json!({ "keys": ["1", "1"], "values": ['a', 'b'] }))
+                .unwrap_err();
+        assert_eq!(e.to_string(), "duplicate map key");
+        let v =
+            serde_json::from_value::<Entries>(json!({ "keys": ["1", "2"], "values": ['a', 'b'] }))
+                .unwrap();
+        assert_eq!(v, Entries(vec![(1, 'a'), (2, 'b')]));
+    }
+}
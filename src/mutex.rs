@@ -3,9 +3,17 @@
 use crate::{DeserializeWith, SerializeWith};
 use core::marker::PhantomData;
 use serde::{ser::Error, Deserializer, Serializer};
+use std::sync::{PoisonError, TryLockError};
 
 /// Adapter for [`Mutex`](std::sync::Mutex)
 ///
+/// Serialization fails if the mutex is poisoned. See [`MutexRecover`] to serialize the data of a
+/// poisoned mutex.
+///
+/// With the default mode [`NonBlockingLock`], serialization also fails if the mutex is locked.
+/// With [`BlockingLock`], serialization waits for the mutex to be unlocked, which deadlocks if the
+/// current thread holds the lock.
+///
 /// # Example
 /// ```
 /// use serdapt as sa;
@@ -19,9 +27,9 @@ use serde::{ser::Error, Deserializer, Serializer};
 /// let v = serde_json::to_value(Foo(Mutex::new(33))).unwrap();
 /// assert_eq!(v, json!("33"));
 /// ```
-pub struct Mutex<F>(PhantomData<F>);
+pub struct Mutex<F, M = NonBlockingLock>(PhantomData<(F, M)>);
 
-impl<F> Mutex<F> {
+impl<F, M> Mutex<F, M> {
     /// Serializes value with adapter
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -42,20 +50,26 @@ impl<F> Mutex<F> {
     }
 }
 
-impl<F, T> SerializeWith<std::sync::Mutex<T>> for Mutex<F>
+impl<F, M, T> SerializeWith<std::sync::Mutex<T>> for Mutex<F, M>
 where
     F: SerializeWith<T>,
+    M: LockMode,
     T: ?Sized,
 {
     fn serialize_with<S: Serializer>(
         value: &std::sync::Mutex<T>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        F::serialize_with(&*value.try_lock().map_err(S::Error::custom)?, serializer)
+        let guard = if M::BLOCKING {
+            value.lock().map_err(S::Error::custom)?
+        } else {
+            value.try_lock().map_err(S::Error::custom)?
+        };
+        F::serialize_with(&*guard, serializer)
     }
 }
 
-impl<'de, F, T> DeserializeWith<'de, std::sync::Mutex<T>> for Mutex<F>
+impl<'de, F, M, T> DeserializeWith<'de, std::sync::Mutex<T>> for Mutex<F, M>
 where
     F: DeserializeWith<'de, T>,
 {
@@ -67,6 +81,94 @@ where
     }
 }
 
+/// Adapter for [`Mutex`](std::sync::Mutex) ignoring poisoning
+///
+/// This adapter behaves like [`Mutex`] but serializes the data of a poisoned mutex instead of
+/// failing. Such data may be in an inconsistent state, as a thread panicked while holding the lock.
+/// Locking behaves according to `M` as with [`Mutex`].
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::sync::Mutex;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "sa::MutexRecover::<sa::Str>")] Mutex<i32>);
+///
+/// let foo = Foo(Mutex::new(33));
+/// let _ = std::panic::catch_unwind(|| {
+///     let _lock = foo.0.lock().unwrap();
+///     panic!();
+/// });
+/// assert!(foo.0.is_poisoned());
+/// let v = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(v, json!("33"));
+/// ```
+pub struct MutexRecover<F, M = NonBlockingLock>(PhantomData<(F, M)>);
+
+impl<F, M> MutexRecover<F, M> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, M, T> SerializeWith<std::sync::Mutex<T>> for MutexRecover<F, M>
+where
+    F: SerializeWith<T>,
+    M: LockMode,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(
+        value: &std::sync::Mutex<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let guard = if M::BLOCKING {
+            value.lock().unwrap_or_else(PoisonError::into_inner)
+        } else {
+            match value.try_lock() {
+                Ok(guard) => guard,
+                Err(TryLockError::Poisoned(e)) => e.into_inner(),
+                Err(e @ TryLockError::WouldBlock) => return Err(S::Error::custom(e)),
+            }
+        };
+        F::serialize_with(&*guard, serializer)
+    }
+}
+
+impl<'de, F, M, T> DeserializeWith<'de, std::sync::Mutex<T>> for MutexRecover<F, M>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<std::sync::Mutex<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Mutex::<F>::deserialize_with(deserializer)
+    }
+}
+
+/// Locking mode used by [`Mutex`], [`MutexRecover`] and [`RwLock`](crate::RwLock)
+pub trait LockMode {
+    /// Whether serialization waits for the lock to be available
+    const BLOCKING: bool;
+}
+
+/// Locking mode failing if the lock is not immediately available
+pub struct NonBlockingLock;
+
+impl LockMode for NonBlockingLock {
+    const BLOCKING: bool = false;
+}
+
+/// Locking mode waiting for the lock to be available
+///
+/// This deadlocks if the lock is already held by the current thread.
+pub struct BlockingLock;
+
+impl LockMode for BlockingLock {
+    const BLOCKING: bool = true;
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -91,4 +193,73 @@ mod tests {
         let _lock = foo.0.lock().unwrap();
         serde_json::to_value(&foo).unwrap_err();
     }
+
+    fn poison(mutex: &Mutex<i32>) {
+        let _ = std::panic::catch_unwind(|| {
+            let _lock = mutex.lock().unwrap();
+            panic!("poisoning mutex");
+        });
+        assert!(mutex.is_poisoned());
+    }
+
+    #[test]
+    fn serializing_fails_if_mutex_is_poisoned() {
+        let foo = Foo(Mutex::new(33));
+        poison(&foo.0);
+        serde_json::to_value(&foo).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Recover(#[serde(with = "crate::MutexRecover::<crate::Str>")] Mutex<i32>);
+
+    #[test]
+    fn mutex_recover_adapter_roundtrips() {
+        let serialized = serde_json::to_value(Recover(Mutex::new(33))).unwrap();
+        assert_eq!(serialized, json!("33"));
+        let deserialized = serde_json::from_value::<Recover>(serialized).unwrap();
+        assert_eq!(*deserialized.0.lock().unwrap(), 33);
+    }
+
+    #[test]
+    fn mutex_recover_serializes_poisoned_mutex() {
+        let foo = Recover(Mutex::new(33));
+        poison(&foo.0);
+        assert_eq!(serde_json::to_value(&foo).unwrap(), json!("33"));
+    }
+
+    #[test]
+    fn mutex_recover_fails_if_mutex_cannot_be_locked() {
+        let foo = Recover(Mutex::new(33));
+        let _lock = foo.0.lock().unwrap();
+        serde_json::to_value(&foo).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct BlockingFoo(
+        #[serde(with = "crate::Mutex::<crate::Str, crate::BlockingLock>")] Mutex<i32>,
+    );
+
+    #[test]
+    fn blocking_mutex_waits_for_lock() {
+        let foo = BlockingFoo(Mutex::new(33));
+        let lock = foo.0.lock().unwrap();
+        std::thread::scope(|scope| {
+            let serialized = scope.spawn(|| serde_json::to_value(&foo).unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            drop(lock);
+            assert_eq!(serialized.join().unwrap(), json!("33"));
+        });
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct BlockingRecover(
+        #[serde(with = "crate::MutexRecover::<crate::Str, crate::BlockingLock>")] Mutex<i32>,
+    );
+
+    #[test]
+    fn blocking_mutex_recover_serializes_poisoned_mutex() {
+        let foo = BlockingRecover(Mutex::new(33));
+        poison(&foo.0);
+        assert_eq!(serde_json::to_value(&foo).unwrap(), json!("33"));
+    }
 }
@@ -0,0 +1,106 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Seq, SerializeWith};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter applying `F` to the items of sequences nested `DEPTH` levels deep
+///
+/// `Nested<DEPTH, F>` behaves like `Seq` nested `DEPTH` times around `F`, e.g. `Nested<2, F>` is
+/// equivalent to [`Seq<Seq<F>>`](Seq). `Nested<0, F>` is equivalent to `F`. This is convenient to
+/// customize how the elements of matrices and tensors are serialized.
+///
+/// Depths up to 8 are supported.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Matrix(#[serde(with = "sa::Nested::<2, sa::Str>")] Vec<Vec<i32>>);
+///
+/// let matrix = Matrix(vec![vec![1, 2], vec![3, 4]]);
+/// let serialized = serde_json::to_value(&matrix).unwrap();
+/// assert_eq!(serialized, json!([["1", "2"], ["3", "4"]]));
+/// let deserialized = serde_json::from_value::<Matrix>(serialized).unwrap();
+/// assert_eq!(deserialized, matrix);
+/// # }
+/// ```
+pub struct Nested<const DEPTH: usize, F>(PhantomData<F>);
+
+impl<const DEPTH: usize, F> Nested<DEPTH, F> {
+    crate::adapter_methods!(pub);
+}
+
+macro_rules! impl_nested {
+    ($($depth:literal => $adapter:ty,)*) => {
+        $(
+            impl<F, T> SerializeWith<T> for Nested<$depth, F>
+            where
+                T: ?Sized,
+                $adapter: SerializeWith<T>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &T,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    <$adapter>::serialize_with(value, serializer)
+                }
+            }
+
+            impl<'de, F, T> DeserializeWith<'de, T> for Nested<$depth, F>
+            where
+                $adapter: DeserializeWith<'de, T>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    <$adapter>::deserialize_with(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_nested! {
+    0 => F,
+    1 => Seq<F>,
+    2 => Seq<Seq<F>>,
+    3 => Seq<Seq<Seq<F>>>,
+    4 => Seq<Seq<Seq<Seq<F>>>>,
+    5 => Seq<Seq<Seq<Seq<Seq<F>>>>>,
+    6 => Seq<Seq<Seq<Seq<Seq<Seq<F>>>>>>,
+    7 => Seq<Seq<Seq<Seq<Seq<Seq<Seq<F>>>>>>>,
+    8 => Seq<Seq<Seq<Seq<Seq<Seq<Seq<Seq<F>>>>>>>>,
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Scalar(#[serde(with = "sa::Nested::<0, sa::Str>")] i32);
+
+    #[test]
+    fn depth_zero_applies_inner_adapter() {
+        check_serialization(Scalar(33), json!("33"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Tensor(#[serde(with = "sa::Nested::<3, sa::Str>")] Vec<Vec<Vec<u8>>>);
+
+    #[test]
+    fn inner_adapter_is_applied_at_depth() {
+        check_serialization(
+            Tensor(vec![vec![vec![1, 2], vec![]], vec![vec![3]]]),
+            json!([[["1", "2"], []], [["3"]]]),
+        );
+    }
+}
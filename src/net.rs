@@ -0,0 +1,540 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{fields, DeserializeWith, SerializeWith, Str, WithEncoding};
+#[cfg(serdapt_core_net)]
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{EnumAccess, Unexpected, VariantAccess, Visitor},
+    Deserialize, Deserializer, Serializer,
+};
+#[cfg(not(serdapt_core_net))]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// Adapter to serialize IP addresses as text
+///
+/// See [`IpEncoding`] for details.
+pub type Ip = IpEncoding<AddrText>;
+
+/// Adapter to serialize IP addresses as octets
+///
+/// See [`IpEncoding`] for details.
+pub type IpOctets<F = crate::Id> = IpEncoding<AddrOctets<F>>;
+
+/// Adapter for [`IpAddr`], [`Ipv4Addr`] and [`Ipv6Addr`]
+///
+/// The mode `M` selects how addresses are serialized:
+/// - [`AddrText`] serializes addresses with their [`Display`](core::fmt::Display) implementation,
+///   e.g. `"127.0.0.1"`.
+/// - [`AddrOctets<F>`] serializes addresses as arrays of 4 or 16 octets, themselves serialized with
+///   `F`. An [`IpAddr`] is serialized as an enum with variants `V4` and `V6`, like `serde` does for
+///   non-human-readable formats.
+///
+/// The [`Ip`] and [`IpOctets`] aliases are provided for convenience.
+///
+/// With Rust 1.77 or later, the address types come from `core::net` and this adapter is available
+/// without the `std` feature. Otherwise, it requires the `std` feature.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Ip")]
+///     text: IpAddr,
+///     #[serde(with = "sa::IpOctets::<sa::Id>")]
+///     v4: Ipv4Addr,
+///     #[serde(with = "sa::IpOctets::<sa::Hex>")]
+///     v6: Ipv6Addr,
+/// }
+///
+/// let foo = Foo {
+///     text: IpAddr::V4(Ipv4Addr::LOCALHOST),
+///     v4: Ipv4Addr::new(192, 168, 0, 1),
+///     v6: Ipv6Addr::LOCALHOST,
+/// };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(
+///     serialized,
+///     json!({
+///         "text": "127.0.0.1",
+///         "v4": [192, 168, 0, 1],
+///         "v6": "00000000000000000000000000000001",
+///     }),
+/// );
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct IpEncoding<M>(PhantomData<M>);
+
+impl<M> IpEncoding<M> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Mode to serialize IP addresses as text
+pub struct AddrText;
+
+/// Mode to serialize IP addresses as octets serialized with `F`
+pub struct AddrOctets<F = crate::Id>(PhantomData<F>);
+
+macro_rules! impl_ip_text {
+    ($($t:ty,)*) => {
+        $(
+            impl SerializeWith<$t> for IpEncoding<AddrText> {
+                fn serialize_with<S: Serializer>(value: &$t, serializer: S) -> Result<S::Ok, S::Error> {
+                    Str::serialize_with(value, serializer)
+                }
+            }
+
+            impl<'de> DeserializeWith<'de, $t> for IpEncoding<AddrText> {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Str::deserialize_with(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_ip_text! {
+    IpAddr,
+    Ipv4Addr,
+    Ipv6Addr,
+}
+
+impl<F> SerializeWith<Ipv4Addr> for IpEncoding<AddrOctets<F>>
+where
+    F: SerializeWith<[u8; 4]>,
+{
+    fn serialize_with<S: Serializer>(value: &Ipv4Addr, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&value.octets(), serializer)
+    }
+}
+
+impl<'de, F> DeserializeWith<'de, Ipv4Addr> for IpEncoding<AddrOctets<F>>
+where
+    F: DeserializeWith<'de, [u8; 4]>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Ipv4Addr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(Ipv4Addr::from)
+    }
+}
+
+impl<F> SerializeWith<Ipv6Addr> for IpEncoding<AddrOctets<F>>
+where
+    F: SerializeWith<[u8; 16]>,
+{
+    fn serialize_with<S: Serializer>(value: &Ipv6Addr, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&value.octets(), serializer)
+    }
+}
+
+impl<'de, F> DeserializeWith<'de, Ipv6Addr> for IpEncoding<AddrOctets<F>>
+where
+    F: DeserializeWith<'de, [u8; 16]>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Ipv6Addr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(Ipv6Addr::from)
+    }
+}
+
+const IP_ADDR_VARIANTS: &[&str] = &["V4", "V6"];
+
+impl<F> SerializeWith<IpAddr> for IpEncoding<AddrOctets<F>>
+where
+    F: SerializeWith<[u8; 4]> + SerializeWith<[u8; 16]>,
+{
+    fn serialize_with<S: Serializer>(value: &IpAddr, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            IpAddr::V4(a) => serializer.serialize_newtype_variant(
+                "IpAddr",
+                0,
+                IP_ADDR_VARIANTS[0],
+                &WithEncoding::<&Self, _>::from(a),
+            ),
+            IpAddr::V6(a) => serializer.serialize_newtype_variant(
+                "IpAddr",
+                1,
+                IP_ADDR_VARIANTS[1],
+                &WithEncoding::<&Self, _>::from(a),
+            ),
+        }
+    }
+}
+
+impl<'de, F> DeserializeWith<'de, IpAddr> for IpEncoding<AddrOctets<F>>
+where
+    F: DeserializeWith<'de, [u8; 4]> + DeserializeWith<'de, [u8; 16]>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<IpAddr, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("IpAddr", IP_ADDR_VARIANTS, IpAddrVisitor::<F>(PhantomData))
+    }
+}
+
+struct IpAddrVisitor<F>(PhantomData<F>);
+
+impl<'de, F> Visitor<'de> for IpAddrVisitor<F>
+where
+    F: DeserializeWith<'de, [u8; 4]> + DeserializeWith<'de, [u8; 16]>,
+{
+    type Value = IpAddr;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an IP address")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        match data.variant()? {
+            (IpAddrKind::V4, v) => v
+                .newtype_variant::<WithEncoding<IpEncoding<AddrOctets<F>>, Ipv4Addr>>()
+                .map(|a| IpAddr::V4(a.into_inner())),
+            (IpAddrKind::V6, v) => v
+                .newtype_variant::<WithEncoding<IpEncoding<AddrOctets<F>>, Ipv6Addr>>()
+                .map(|a| IpAddr::V6(a.into_inner())),
+        }
+    }
+}
+
+enum IpAddrKind {
+    V4,
+    V6,
+}
+
+impl<'de> Deserialize<'de> for IpAddrKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(IpAddrKindVisitor)
+    }
+}
+
+struct IpAddrKindVisitor;
+
+impl<'de> Visitor<'de> for IpAddrKindVisitor {
+    type Value = IpAddrKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`V4` or `V6`")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            0 => Ok(IpAddrKind::V4),
+            1 => Ok(IpAddrKind::V6),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "V4" => Ok(IpAddrKind::V4),
+            "V6" => Ok(IpAddrKind::V6),
+            _ => Err(E::unknown_variant(v, IP_ADDR_VARIANTS)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            b"V4" => Ok(IpAddrKind::V4),
+            b"V6" => Ok(IpAddrKind::V6),
+            _ => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+/// Adapter to serialize socket addresses as text
+///
+/// See [`SocketEncoding`] for details.
+pub type Socket = SocketEncoding<AddrText>;
+
+/// Adapter to serialize socket addresses as a struct with IP address and port fields
+///
+/// See [`SocketEncoding`] for details.
+pub type SocketStruct<F = Ip> = SocketEncoding<IpPort<F>>;
+
+/// Adapter for [`SocketAddr`], [`SocketAddrV4`] and [`SocketAddrV6`]
+///
+/// The mode `M` selects how socket addresses are serialized:
+/// - [`AddrText`] serializes addresses with their [`Display`](core::fmt::Display) implementation,
+///   e.g. `"127.0.0.1:80"` or `"[::1]:80"`.
+/// - [`IpPort<F>`] serializes addresses as a struct with fields `ip` and `port`, the IP address
+///   being serialized with `F`. The flow information and scope ID of IPv6 socket addresses are not
+///   preserved.
+///
+/// The [`Socket`] and [`SocketStruct`] aliases are provided for convenience.
+///
+/// Like [`IpEncoding`], this adapter only requires the `std` feature before Rust 1.77.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::net::{Ipv6Addr, SocketAddr};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Socket")]
+///     text: SocketAddr,
+///     #[serde(with = "sa::SocketStruct::<sa::Ip>")]
+///     fields: SocketAddr,
+/// }
+///
+/// let foo = Foo {
+///     text: (Ipv6Addr::LOCALHOST, 80).into(),
+///     fields: ([10, 0, 0, 1], 443).into(),
+/// };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(
+///     serialized,
+///     json!({ "text": "[::1]:80", "fields": { "ip": "10.0.0.1", "port": 443 } }),
+/// );
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct SocketEncoding<M>(PhantomData<M>);
+
+impl<M> SocketEncoding<M> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Mode to serialize socket addresses as a struct with IP address and port fields
+pub struct IpPort<F = Ip>(PhantomData<F>);
+
+macro_rules! impl_socket {
+    ($($t:ident $ip:ident $new:expr,)*) => {
+        $(
+            impl SerializeWith<$t> for SocketEncoding<AddrText> {
+                fn serialize_with<S: Serializer>(value: &$t, serializer: S) -> Result<S::Ok, S::Error> {
+                    Str::serialize_with(value, serializer)
+                }
+            }
+
+            impl<'de> DeserializeWith<'de, $t> for SocketEncoding<AddrText> {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Str::deserialize_with(deserializer)
+                }
+            }
+
+            impl<F> SerializeWith<$t> for SocketEncoding<IpPort<F>>
+            where
+                F: SerializeWith<$ip>,
+            {
+                fn serialize_with<S: Serializer>(value: &$t, serializer: S) -> Result<S::Ok, S::Error> {
+                    let ip: &$ip = &value.ip();
+                    fields::serialize_pair(
+                        serializer,
+                        stringify!($t),
+                        SOCKET_FIELDS,
+                        &WithEncoding::<&F, _>::from(ip),
+                        &value.port(),
+                    )
+                }
+            }
+
+            impl<'de, F> DeserializeWith<'de, $t> for SocketEncoding<IpPort<F>>
+            where
+                F: DeserializeWith<'de, $ip>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let (ip, port) = fields::deserialize_pair::<_, WithEncoding<F, $ip>, u16>(
+                        deserializer,
+                        stringify!($t),
+                        SOCKET_FIELDS,
+                    )?;
+                    let new: fn($ip, u16) -> $t = $new;
+                    Ok(new(ip.into_inner(), port))
+                }
+            }
+        )*
+    };
+}
+
+const SOCKET_FIELDS: &[&str; 2] = &["ip", "port"];
+
+impl_socket! {
+    SocketAddr IpAddr SocketAddr::new,
+    SocketAddrV4 Ipv4Addr SocketAddrV4::new,
+    SocketAddrV6 Ipv6Addr |ip, port| SocketAddrV6::new(ip, port, 0, 0),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::Ip")] IpAddr);
+
+    #[test]
+    fn ip_text_roundtrips() {
+        check_serialization(Text(IpAddr::V4(Ipv4Addr::LOCALHOST)), json!("127.0.0.1"));
+        check_serialization(Text(IpAddr::V6(Ipv6Addr::LOCALHOST)), json!("::1"));
+    }
+
+    #[test]
+    fn malformed_ip_text_fails() {
+        serde_json::from_value::<Text>(json!("127.0.0")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Octets(#[serde(with = "sa::IpOctets::<sa::Id>")] IpAddr);
+
+    #[test]
+    fn ip_octets_roundtrip() {
+        check_serialization(
+            Octets(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+            json!({ "V4": [10, 0, 0, 1] }),
+        );
+        check_serialization(
+            Octets(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            json!({ "V6": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1] }),
+        );
+    }
+
+    #[test]
+    fn ip_octets_roundtrip_with_bincode() {
+        let original = Octets(IpAddr::V6(Ipv6Addr::LOCALHOST));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Octets>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn wrong_octet_count_fails() {
+        serde_json::from_value::<Octets>(json!({ "V4": [10, 0, 0] })).unwrap_err();
+        serde_json::from_value::<Octets>(json!({ "V6": [10, 0, 0, 1] })).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct StrOctets(#[serde(with = "sa::IpOctets::<sa::Array<sa::Str>>")] Ipv4Addr);
+
+    #[test]
+    fn ip_octets_use_inner_adapter() {
+        check_serialization(
+            StrOctets(Ipv4Addr::new(10, 0, 0, 1)),
+            json!(["10", "0", "0", "1"]),
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct SocketText(#[serde(with = "sa::Socket")] SocketAddr);
+
+    #[test]
+    fn socket_text_roundtrips() {
+        check_serialization(
+            SocketText(([127, 0, 0, 1], 80).into()),
+            json!("127.0.0.1:80"),
+        );
+        check_serialization(
+            SocketText((Ipv6Addr::LOCALHOST, 80).into()),
+            json!("[::1]:80"),
+        );
+    }
+
+    #[test]
+    fn malformed_socket_text_fails() {
+        serde_json::from_value::<SocketText>(json!("::1:80")).unwrap_err();
+        serde_json::from_value::<SocketText>(json!("127.0.0.1")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct SocketFields(#[serde(with = "sa::SocketStruct::<sa::IpOctets>")] SocketAddrV6);
+
+    #[test]
+    fn socket_struct_roundtrips() {
+        check_serialization(
+            SocketFields(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 0)),
+            json!({
+                "ip": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                "port": 80,
+            }),
+        );
+    }
+
+    #[test]
+    fn socket_struct_roundtrips_with_bincode() {
+        let original = SocketFields(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 0));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<SocketFields>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn socket_struct_missing_port_fails() {
+        serde_json::from_value::<SocketFields>(
+            json!({ "ip": [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1] }),
+        )
+        .unwrap_err();
+    }
+}
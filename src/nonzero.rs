@@ -0,0 +1,128 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter for nonzero integers such as [`NonZeroU32`](core::num::NonZeroU32)
+///
+/// The inner integer is serialized with `F`. Deserializing zero results in an error.
+///
+/// # Example
+/// ```
+/// use core::num::NonZeroU64;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::NonZero::<serdapt::Str>")] NonZeroU64);
+///
+/// let foo = Foo(NonZeroU64::new(33).unwrap());
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("33"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!("0")).is_err());
+/// ```
+pub struct NonZero<F = crate::Id>(PhantomData<F>);
+
+impl<F> NonZero<F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+macro_rules! impl_nonzero {
+    ($($nonzero:ident $t:ty,)*) => {
+        $(
+            impl<F> SerializeWith<core::num::$nonzero> for NonZero<F>
+            where
+                F: SerializeWith<$t>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &core::num::$nonzero,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    F::serialize_with(&value.get(), serializer)
+                }
+            }
+
+            impl<'de, F> DeserializeWith<'de, core::num::$nonzero> for NonZero<F>
+            where
+                F: DeserializeWith<'de, $t>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<core::num::$nonzero, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    core::num::$nonzero::new(F::deserialize_with(deserializer)?)
+                        .ok_or_else(|| D::Error::custom("expected a nonzero integer"))
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero! {
+    NonZeroU8 u8,
+    NonZeroU16 u16,
+    NonZeroU32 u32,
+    NonZeroU64 u64,
+    NonZeroU128 u128,
+    NonZeroUsize usize,
+    NonZeroI8 i8,
+    NonZeroI16 i16,
+    NonZeroI32 i32,
+    NonZeroI64 i64,
+    NonZeroI128 i128,
+    NonZeroIsize isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use core::num::{NonZeroI8, NonZeroU64};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::NonZero::<crate::Str>")] NonZeroU64);
+
+    #[test]
+    fn nonzero_adapter_roundtrips() {
+        check_serialization(Foo(NonZeroU64::new(33).unwrap()), json!("33"));
+    }
+
+    #[test]
+    fn zero_fails() {
+        serde_json::from_value::<Foo>(json!("0")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Signed(#[serde(with = "crate::NonZero::<crate::Id>")] NonZeroI8);
+
+    #[test]
+    fn signed_nonzero_adapter_roundtrips() {
+        check_serialization(Signed(NonZeroI8::new(-3).unwrap()), json!(-3));
+    }
+
+    #[test]
+    fn signed_zero_fails() {
+        serde_json::from_value::<Signed>(json!(0)).unwrap_err();
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Adapter serializing a [`Result`](core::result::Result) as an [`Option`](core::option::Option),
+/// discarding the error
+///
+/// `Ok(x)` is serialized as `Some(x)` with `F`, and `Err(_)` is serialized as `None`. When
+/// deserializing, `Some(x)` becomes `Ok(x)` and `None` becomes `Err(E::default())`.
+///
+/// This is lossy: the error payload is never serialized, so deserialization only recovers the
+/// default error. This is useful when errors are not meaningfully serializable, e.g. for logging.
+/// [`Result`](crate::Result) should be used instead to serialize the error.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Failure;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::OkOption::<sa::Str>")] Result<i32, Failure>);
+///
+/// let cases = [(Foo(Ok(33)), json!("33")), (Foo(Err(Failure)), json!(null))];
+///
+/// for (foo, expected) in cases {
+///     let serialized = serde_json::to_value(&foo).unwrap();
+///     assert_eq!(serialized, expected);
+///     let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+///     assert_eq!(deserialized, foo);
+/// }
+/// ```
+pub struct OkOption<F = crate::Id>(PhantomData<F>);
+
+impl<F> OkOption<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T, E> SerializeWith<core::result::Result<T, E>> for OkOption<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &core::result::Result<T, E>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = value.as_ref().ok().map(WithEncoding::<&F, &T>::from);
+        Serialize::serialize(&value, serializer)
+    }
+}
+
+impl<'de, F, T, E> DeserializeWith<'de, core::result::Result<T, E>> for OkOption<F>
+where
+    F: DeserializeWith<'de, T>,
+    E: Default,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<core::result::Result<T, E>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let x: core::option::Option<WithEncoding<F, T>> = Deserialize::deserialize(deserializer)?;
+        Ok(x.map(WithEncoding::into_inner).ok_or_else(E::default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Failure(u8);
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::OkOption::<sa::Str>")] Result<u32, Failure>);
+
+    #[test]
+    fn ok_roundtrips() {
+        check_serialization(Foo(Ok(33)), json!("33"));
+    }
+
+    #[test]
+    fn err_deserializes_as_default_error() {
+        let serialized = serde_json::to_value(Foo(Err(Failure(3)))).unwrap();
+        assert_eq!(serialized, json!(null));
+        let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+        assert_eq!(deserialized, Foo(Err(Failure(0))));
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::{cell::OnceCell, marker::PhantomData};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Adapter for cells initialized once, such as [`OnceCell`] and `OnceLock`
+///
+/// An initialized cell is serialized like [`Some`] wrapping its value serialized with `F`, and an
+/// empty cell is serialized like [`None`]. This matches the encoding of
+/// [`Option<F>`](crate::Option).
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::cell::OnceCell;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "sa::Once::<sa::Str>")] OnceCell<i32>);
+///
+/// let v = serde_json::to_value(Foo(OnceCell::from(33))).unwrap();
+/// assert_eq!(v, json!("33"));
+/// let v = serde_json::to_value(Foo(OnceCell::new())).unwrap();
+/// assert_eq!(v, json!(null));
+/// ```
+pub struct Once<F = crate::Id>(PhantomData<F>);
+
+impl<F> Once<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<OnceCell<T>> for Once<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &OnceCell<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = value.get().map(WithEncoding::<&F, &T>::from);
+        Serialize::serialize(&value, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, OnceCell<T>> for Once<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<OnceCell<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <core::option::Option<WithEncoding<F, T>>>::deserialize(deserializer)?;
+        Ok(value.map_or_else(OnceCell::new, |v| v.into_inner().into()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F, T> SerializeWith<std::sync::OnceLock<T>> for Once<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &std::sync::OnceLock<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = value.get().map(WithEncoding::<&F, &T>::from);
+        Serialize::serialize(&value, serializer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, F, T> DeserializeWith<'de, std::sync::OnceLock<T>> for Once<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<std::sync::OnceLock<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <core::option::Option<WithEncoding<F, T>>>::deserialize(deserializer)?;
+        Ok(value.map_or_else(std::sync::OnceLock::new, |v| v.into_inner().into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use core::cell::OnceCell;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Cell(#[serde(with = "sa::Once::<sa::Str>")] OnceCell<i32>);
+
+    #[test]
+    fn initialized_once_cell_roundtrips() {
+        check_serialization(Cell(OnceCell::from(3)), json!("3"));
+    }
+
+    #[test]
+    fn empty_once_cell_roundtrips() {
+        check_serialization(Cell(OnceCell::new()), json!(null));
+    }
+
+    #[test]
+    fn once_cell_roundtrips_with_bincode() {
+        for cell in [Cell(OnceCell::new()), Cell(OnceCell::from(3))] {
+            let serialized = bincode::serialize(&cell).unwrap();
+            assert_eq!(bincode::deserialize::<Cell>(&serialized).unwrap(), cell);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Lock(#[serde(with = "sa::Once::<sa::Str>")] std::sync::OnceLock<i32>);
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn once_lock_roundtrips() {
+        let serialized = serde_json::to_value(Lock(3.into())).unwrap();
+        assert_eq!(serialized, json!("3"));
+        let deserialized = serde_json::from_value::<Lock>(serialized).unwrap();
+        assert_eq!(deserialized.0.get(), Some(&3));
+        let deserialized = serde_json::from_value::<Lock>(json!(null)).unwrap();
+        assert_eq!(deserialized.0.get(), None);
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{
+    content::{Content, ContentRefDeserializer},
+    DeserializeWith, Seq, SerializeWith,
+};
+use core::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Adapter for collections that may be serialized as a single element
+///
+/// When deserializing, either a sequence or a single element is accepted, each element being
+/// deserialized with `F`. A single element results in a collection containing only this element.
+/// The input is buffered to tell both shapes apart, which requires a self-describing format.
+///
+/// When serializing, `M` selects whether a collection with exactly one element is serialized as
+/// this element alone. See [`OneOrManyMode`] for the available modes.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Config {
+///     #[serde(with = "sa::OneOrMany::<sa::Id>")]
+///     tags: Vec<String>,
+/// }
+///
+/// let config = serde_json::from_value::<Config>(json!({ "tags": "x" })).unwrap();
+/// assert_eq!(config.tags, ["x"]);
+/// assert_eq!(serde_json::to_value(&config).unwrap(), json!({ "tags": "x" }));
+/// let config = serde_json::from_value::<Config>(json!({ "tags": ["x", "y"] })).unwrap();
+/// assert_eq!(config.tags, ["x", "y"]);
+/// assert_eq!(serde_json::to_value(&config).unwrap(), json!({ "tags": ["x", "y"] }));
+/// # }
+/// ```
+pub struct OneOrMany<F = crate::Id, M = PreferOne>(PhantomData<(F, M)>);
+
+impl<F, M> OneOrMany<F, M> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Serialization mode used by [`OneOrMany`]
+pub trait OneOrManyMode {
+    /// Whether a collection with exactly one element is serialized as this element alone
+    const UNWRAP_SINGLE: bool;
+}
+
+/// Mode serializing a collection with exactly one element as this element alone
+pub struct PreferOne;
+
+impl OneOrManyMode for PreferOne {
+    const UNWRAP_SINGLE: bool = true;
+}
+
+/// Mode always serializing a collection as a sequence
+pub struct PreferMany;
+
+impl OneOrManyMode for PreferMany {
+    const UNWRAP_SINGLE: bool = false;
+}
+
+impl<F, M, C, T> SerializeWith<C> for OneOrMany<F, M>
+where
+    F: SerializeWith<T>,
+    M: OneOrManyMode,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        if M::UNWRAP_SINGLE {
+            let mut items = container.into_iter();
+            if let (Some(item), None) = (items.next(), items.next()) {
+                return F::serialize_with(item, serializer);
+            }
+        }
+        Seq::<F>::serialize_with(container, serializer)
+    }
+}
+
+impl<'de, F, M, C> DeserializeWith<'de, C> for OneOrMany<F, M>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let human_readable = deserializer.is_human_readable();
+        let content = Content::deserialize(deserializer)?;
+        let deserializer = ContentRefDeserializer::<D::Error>::new(&content, human_readable);
+        match content {
+            Content::Seq(_) => Seq::<F>::deserialize_with(deserializer),
+            _ => F::deserialize_with(deserializer).map(|item| core::iter::once(item).collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::OneOrMany::<sa::Str>")] Vec<u32>);
+
+    #[test]
+    fn single_element_roundtrips() {
+        check_serialization(Foo(vec![3]), json!("3"));
+    }
+
+    #[test]
+    fn many_elements_roundtrip() {
+        check_serialization(Foo(vec![3, 4]), json!(["3", "4"]));
+        check_serialization(Foo(Vec::new()), json!([]));
+    }
+
+    #[test]
+    fn single_element_in_seq_is_accepted() {
+        let deserialized = serde_json::from_value::<Foo>(json!(["3"])).unwrap();
+        assert_eq!(deserialized, Foo(vec![3]));
+    }
+
+    #[test]
+    fn invalid_element_fails() {
+        serde_json::from_value::<Foo>(json!(3)).unwrap_err();
+        serde_json::from_value::<Foo>(json!(["3", 4])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Many(#[serde(with = "sa::OneOrMany::<sa::Id, sa::PreferMany>")] Vec<u32>);
+
+    #[test]
+    fn prefer_many_serializes_single_element_as_seq() {
+        check_serialization(Many(vec![3]), json!([3]));
+        let deserialized = serde_json::from_value::<Many>(json!(3)).unwrap();
+        assert_eq!(deserialized, Many(vec![3]));
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{ser::Error as _, Deserializer, Serializer};
+
+/// Adapter for [`Option`] omitting `None` instead of serializing it as null
+///
+/// `Some(x)` is serialized as `x` with `F`, without any wrapper. `None` has no representation and
+/// must be skipped, so the field must be annotated with
+/// `#[serde(default, skip_serializing_if = "...")]`:
+/// - `skip_serializing_if` must point to [`OptionFlat::is_none`] or [`Option::is_none`].
+///   Serializing `None` without it fails.
+/// - `default` makes a missing field deserialize as `None`. A present field is always
+///   deserialized as `Some` with `F`, so an explicit null is only accepted if `F` accepts it.
+///
+/// This differs from [`Option`](crate::Option), which serializes `None` as null.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(
+///         default,
+///         skip_serializing_if = "sa::OptionFlat::<sa::Str>::is_none",
+///         with = "sa::OptionFlat::<sa::Str>"
+///     )]
+///     n: Option<u32>,
+/// }
+///
+/// let cases = [
+///     (Foo { n: Some(3) }, json!({ "n": "3" })),
+///     (Foo { n: None }, json!({})),
+/// ];
+///
+/// for (foo, expected) in cases {
+///     let serialized = serde_json::to_value(&foo).unwrap();
+///     assert_eq!(serialized, expected);
+///     let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+///     assert_eq!(deserialized, foo);
+/// }
+/// ```
+pub struct OptionFlat<F = crate::Id>(PhantomData<F>);
+
+impl<F> OptionFlat<F> {
+    crate::adapter_methods!(pub);
+
+    /// Returns whether the option is `None`, for use with `#[serde(skip_serializing_if = "...")]`
+    pub fn is_none<T>(value: &Option<T>) -> bool {
+        value.is_none()
+    }
+}
+
+impl<F, T> SerializeWith<Option<T>> for OptionFlat<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(x) => F::serialize_with(x, serializer),
+            None => Err(S::Error::custom(
+                "`None` cannot be serialized with `OptionFlat` and must be skipped",
+            )),
+        }
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, Option<T>> for OptionFlat<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo {
+        #[serde(
+            default,
+            skip_serializing_if = "sa::OptionFlat::<sa::Str>::is_none",
+            with = "sa::OptionFlat::<sa::Str>"
+        )]
+        n: Option<i32>,
+    }
+
+    #[test]
+    fn some_is_serialized_without_wrapper() {
+        check_serialization(Foo { n: Some(-2) }, json!({ "n": "-2" }));
+    }
+
+    #[test]
+    fn none_is_skipped() {
+        check_serialization(Foo { n: None }, json!({}));
+    }
+
+    #[test]
+    fn null_is_rejected() {
+        serde_json::from_value::<Foo>(json!({ "n": null })).unwrap_err();
+    }
+
+    #[derive(Debug, Serialize)]
+    struct NotSkipped(#[serde(with = "sa::OptionFlat::<sa::Id>")] Option<i32>);
+
+    #[test]
+    fn unskipped_none_fails_to_serialize() {
+        serde_json::to_value(NotSkipped(None)).unwrap_err();
+    }
+}
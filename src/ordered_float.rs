@@ -0,0 +1,88 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter for [`ordered_float::OrderedFloat`]
+///
+/// The wrapped float is serialized with `F`. When deserializing, a float is deserialized with `F`
+/// and wrapped. NaN is handled like any other value, i.e. it is only supported if `F` supports it.
+/// [`Float`](crate::Float) can be used as `F` to support NaN in formats like JSON.
+///
+/// This adapter is available with the `ordered-float` feature.
+///
+/// # Example
+/// ```
+/// use ordered_float::OrderedFloat;
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Seq::<sa::OrderedFloat<sa::Float>>")] Vec<OrderedFloat<f64>>);
+///
+/// let foo = Foo(vec![OrderedFloat(1.5), OrderedFloat(f64::NAN)]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1.5", "NaN"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct OrderedFloat<F = crate::Id>(PhantomData<F>);
+
+impl<F> OrderedFloat<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<ordered_float::OrderedFloat<T>> for OrderedFloat<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &ordered_float::OrderedFloat<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&value.0, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, ordered_float::OrderedFloat<T>> for OrderedFloat<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<ordered_float::OrderedFloat<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(ordered_float::OrderedFloat)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use ordered_float::OrderedFloat;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Seq::<sa::OrderedFloat>")] Vec<OrderedFloat<f64>>);
+
+    #[test]
+    fn ordered_floats_roundtrip_in_seq() {
+        check_serialization(
+            Foo(vec![OrderedFloat(-1.0), OrderedFloat(2.5)]),
+            json!([-1.0, 2.5]),
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Text(#[serde(with = "sa::Seq::<sa::OrderedFloat<sa::Float>>")] Vec<OrderedFloat<f32>>);
+
+    #[test]
+    fn nan_roundtrips_through_inner_adapter() {
+        let original = Text(vec![OrderedFloat(f32::NAN), OrderedFloat(0.5)]);
+        check_serialization(original, json!(["NaN", "0.5"]));
+    }
+}
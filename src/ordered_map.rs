@@ -0,0 +1,144 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Id, Map, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, marker::PhantomData};
+use serde::{Deserializer, Serializer};
+
+/// Map adapter serializing entries in the order defined by `O`
+///
+/// `F` is used to serialize keys and `G` is used to serialize values, like [`Map`] does. Entries
+/// are sorted with [`EntryOrder::compare`] before being serialized, which gives a deterministic
+/// output in an order other than the natural order of the map, e.g. for canonical serialization.
+/// The sort is stable, so entries comparing equal are serialized in iteration order.
+///
+/// Serializing allocates a temporary buffer holding references to all entries in order to sort
+/// them. Deserialization behaves like [`Map`] and collects entries in input order.
+///
+/// [`ValuesAscending`] and [`KeysDescending`] are provided for common orders.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Scores(
+///     #[serde(with = "sa::OrderedMap::<sa::ValuesAscending, sa::Id, sa::Id>")]
+///     BTreeMap<String, u32>,
+/// );
+///
+/// let scores = Scores(BTreeMap::from([("a".into(), 3), ("b".into(), 1), ("c".into(), 2)]));
+/// let serialized = serde_json::to_string(&scores).unwrap();
+/// assert_eq!(serialized, r#"{"b":1,"c":2,"a":3}"#);
+/// let deserialized = serde_json::from_str::<Scores>(&serialized).unwrap();
+/// assert_eq!(deserialized, scores);
+/// # }
+/// ```
+pub struct OrderedMap<O, F = Id, G = Id>(PhantomData<(O, F, G)>);
+
+impl<O, F, G> OrderedMap<O, F, G> {
+    crate::adapter_methods!(pub);
+}
+
+/// Order of the entries serialized by [`OrderedMap`]
+pub trait EntryOrder<K, V> {
+    /// Compares two entries
+    fn compare(a: (&K, &V), b: (&K, &V)) -> Ordering;
+}
+
+/// Order of entries by ascending value
+pub struct ValuesAscending;
+
+impl<K, V> EntryOrder<K, V> for ValuesAscending
+where
+    V: Ord,
+{
+    fn compare(a: (&K, &V), b: (&K, &V)) -> Ordering {
+        a.1.cmp(b.1)
+    }
+}
+
+/// Order of entries by descending key
+pub struct KeysDescending;
+
+impl<K, V> EntryOrder<K, V> for KeysDescending
+where
+    K: Ord,
+{
+    fn compare(a: (&K, &V), b: (&K, &V)) -> Ordering {
+        b.0.cmp(a.0)
+    }
+}
+
+impl<O, F, G, C, K, V> SerializeWith<C> for OrderedMap<O, F, G>
+where
+    O: EntryOrder<K, V>,
+    F: SerializeWith<K>,
+    G: SerializeWith<V>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entries = container.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|&a, &b| O::compare(a, b));
+        serializer.collect_map(entries.into_iter().map(|(k, v)| {
+            (
+                WithEncoding::<&F, _>::from(k),
+                WithEncoding::<&G, _>::from(v),
+            )
+        }))
+    }
+}
+
+impl<'de, O, F, G, C> DeserializeWith<'de, C> for OrderedMap<O, F, G>
+where
+    Map<F, G>: DeserializeWith<'de, C>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Map::<F, G>::deserialize_with(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as sa;
+    use alloc::{collections::BTreeMap, string::String};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(
+        #[serde(with = "sa::OrderedMap::<sa::KeysDescending, sa::Str, sa::Id>")]
+        BTreeMap<u32, char>,
+    );
+
+    #[test]
+    fn entries_are_serialized_in_custom_order() {
+        let foo = Foo(BTreeMap::from([(1, 'a'), (10, 'b'), (2, 'c')]));
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serialized, r#"{"10":"b","2":"c","1":"a"}"#);
+        assert_eq!(serde_json::from_str::<Foo>(&serialized).unwrap(), foo);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Scores(
+        #[serde(with = "sa::OrderedMap::<sa::ValuesAscending, sa::Id, sa::Id>")]
+        BTreeMap<String, u8>,
+    );
+
+    #[test]
+    fn ties_keep_iteration_order() {
+        let scores = Scores(BTreeMap::from([
+            ("b".into(), 1),
+            ("a".into(), 1),
+            ("c".into(), 0),
+        ]));
+        let serialized = serde_json::to_string(&scores).unwrap();
+        assert_eq!(serialized, r#"{"c":0,"a":1,"b":1}"#);
+    }
+}
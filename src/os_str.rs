@@ -0,0 +1,176 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, StrictUtf8, Utf8Mode};
+use core::marker::PhantomData;
+use serde::{de::Deserialize, ser::Error as _, Deserializer, Serializer};
+use std::{
+    ffi::{self, OsString},
+    string::String,
+};
+
+/// Adapter to serialize OS strings as UTF-8 strings, failing on strings that are not valid UTF-8
+///
+/// See [`OsStrEncoding`] for details.
+pub type OsStr = OsStrEncoding<StrictUtf8>;
+
+/// Adapter to serialize OS strings as their raw bytes
+///
+/// See [`OsStrEncoding`] for details.
+#[cfg(unix)]
+pub type OsStrBytes = OsStrEncoding<RawBytes>;
+
+/// Adapter for [`OsString`] and [`OsStr`](ffi::OsStr)
+///
+/// The mode `M` selects the representation:
+/// - [`StrictUtf8`] serializes as a UTF-8 string and fails if the OS string is not valid UTF-8.
+/// - [`LossyUtf8`](crate::LossyUtf8) serializes as a UTF-8 string, replacing invalid sequences
+///   with `U+FFFD REPLACEMENT CHARACTER`.
+/// - [`RawBytes`] serializes the raw bytes of the OS string, which is lossless. This mode is only
+///   available on Unix, where OS strings are arbitrary byte sequences. On Windows, OS strings are
+///   potentially ill-formed UTF-16 and no lossless mode is provided.
+///
+/// In all modes, [`OsString`] can be deserialized. The [`OsStr`] and [`OsStrBytes`] aliases are
+/// provided for convenience.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::{collections::HashMap, ffi::OsString};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Env(#[serde(with = "sa::Map::<sa::OsStr, sa::Id>")] HashMap<OsString, String>);
+///
+/// let env = Env(HashMap::from([("HOME".into(), "/home/me".to_owned())]));
+/// let serialized = serde_json::to_value(&env).unwrap();
+/// assert_eq!(serialized, json!({ "HOME": "/home/me" }));
+/// let deserialized = serde_json::from_value::<Env>(serialized).unwrap();
+/// assert_eq!(deserialized, env);
+/// ```
+pub struct OsStrEncoding<M>(PhantomData<M>);
+
+impl<M> OsStrEncoding<M> {
+    crate::adapter_methods!(pub);
+}
+
+/// Mode serializing OS strings as their raw bytes
+#[cfg(unix)]
+pub struct RawBytes;
+
+impl<M> SerializeWith<ffi::OsStr> for OsStrEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn serialize_with<S: Serializer>(value: &ffi::OsStr, serializer: S) -> Result<S::Ok, S::Error> {
+        if M::LOSSY {
+            serializer.serialize_str(&value.to_string_lossy())
+        } else {
+            let s = value
+                .to_str()
+                .ok_or_else(|| S::Error::custom("OS string is not valid UTF-8"))?;
+            serializer.serialize_str(s)
+        }
+    }
+}
+
+impl<M> SerializeWith<OsString> for OsStrEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn serialize_with<S: Serializer>(value: &OsString, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_with(value.as_os_str(), serializer)
+    }
+}
+
+impl<'de, M> DeserializeWith<'de, OsString> for OsStrEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<OsString, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(OsString::from)
+    }
+}
+
+#[cfg(unix)]
+impl SerializeWith<ffi::OsStr> for OsStrEncoding<RawBytes> {
+    fn serialize_with<S: Serializer>(value: &ffi::OsStr, serializer: S) -> Result<S::Ok, S::Error> {
+        use std::os::unix::ffi::OsStrExt;
+        crate::Bytes::serialize_with(value.as_bytes(), serializer)
+    }
+}
+
+#[cfg(unix)]
+impl SerializeWith<OsString> for OsStrEncoding<RawBytes> {
+    fn serialize_with<S: Serializer>(value: &OsString, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_with(value.as_os_str(), serializer)
+    }
+}
+
+#[cfg(unix)]
+impl<'de> DeserializeWith<'de, OsString> for OsStrEncoding<RawBytes> {
+    fn deserialize_with<D>(deserializer: D) -> Result<OsString, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::os::unix::ffi::OsStringExt;
+        crate::Bytes::deserialize_with(deserializer).map(OsString::from_vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::{collections::HashMap, ffi::OsString};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::OsStr")] OsString);
+
+    #[test]
+    fn os_string_roundtrips_as_string() {
+        check_serialization(Foo("abc".into()), json!("abc"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Env(#[serde(with = "sa::Map::<sa::OsStr, sa::Id>")] HashMap<OsString, String>);
+
+    #[test]
+    fn os_strings_can_be_map_keys() {
+        check_serialization(
+            Env(HashMap::from([("PATH".into(), "/bin".into())])),
+            json!({ "PATH": "/bin" }),
+        );
+    }
+
+    #[cfg(unix)]
+    fn non_utf8_os_string() -> OsString {
+        use std::os::unix::ffi::OsStringExt;
+        OsString::from_vec(b"a\xffb".to_vec())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn strict_mode_rejects_non_utf8_os_string() {
+        serde_json::to_value(Foo(non_utf8_os_string())).unwrap_err();
+    }
+
+    #[cfg(unix)]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Raw(#[serde(with = "sa::OsStrBytes")] OsString);
+
+    #[cfg(unix)]
+    #[test]
+    fn raw_bytes_mode_is_lossless() {
+        check_serialization(Raw(non_utf8_os_string()), json!([b'a', 0xff, b'b']));
+        let serialized = bincode::serialize(&Raw(non_utf8_os_string())).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Raw>(&serialized).unwrap(),
+            Raw(non_utf8_os_string())
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{de::Deserialize, ser::Error as _, Deserializer, Serializer};
+use std::{
+    path::{Path, PathBuf},
+    string::String,
+};
+
+/// Adapter to serialize paths as UTF-8 strings, failing on paths that are not valid UTF-8
+///
+/// See [`PathEncoding`] for details.
+pub type PathUtf8 = PathEncoding<StrictUtf8>;
+
+/// Adapter to serialize paths as UTF-8 strings, replacing invalid UTF-8 sequences
+///
+/// See [`PathEncoding`] for details.
+pub type LossyPathUtf8 = PathEncoding<LossyUtf8>;
+
+/// Adapter for [`Path`] and [`PathBuf`] serialized as UTF-8 strings
+///
+/// Paths are not guaranteed to be valid UTF-8 on all platforms. The mode `M` selects what happens
+/// when serializing such a path:
+/// - [`StrictUtf8`] fails.
+/// - [`LossyUtf8`] replaces invalid sequences with `U+FFFD REPLACEMENT CHARACTER`, as
+///   [`Path::to_string_lossy`] does. The deserialized path then differs from the original one.
+///
+/// Non-UTF-8 paths therefore cannot roundtrip in either mode. [`PathBuf`] and `&Path` can be
+/// deserialized, the latter only from a borrowed string.
+///
+/// The [`PathUtf8`] and [`LossyPathUtf8`] aliases are provided for convenience.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::path::PathBuf;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Seq::<sa::PathUtf8>")] Vec<PathBuf>);
+///
+/// let foo = Foo(vec!["a/b".into(), "c".into()]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["a/b", "c"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct PathEncoding<M>(PhantomData<M>);
+
+impl<M> PathEncoding<M> {
+    crate::adapter_methods!(pub);
+}
+
+/// Mode selecting how [`PathEncoding`] and [`OsStrEncoding`](crate::OsStrEncoding) handle values
+/// that are not valid UTF-8
+pub trait Utf8Mode {
+    /// Whether invalid UTF-8 sequences are replaced instead of causing an error
+    const LOSSY: bool;
+}
+
+/// Mode failing on values that are not valid UTF-8
+pub struct StrictUtf8;
+
+impl Utf8Mode for StrictUtf8 {
+    const LOSSY: bool = false;
+}
+
+/// Mode replacing invalid UTF-8 sequences
+pub struct LossyUtf8;
+
+impl Utf8Mode for LossyUtf8 {
+    const LOSSY: bool = true;
+}
+
+impl<M> SerializeWith<Path> for PathEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn serialize_with<S: Serializer>(value: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        if M::LOSSY {
+            serializer.serialize_str(&value.to_string_lossy())
+        } else {
+            let s = value
+                .to_str()
+                .ok_or_else(|| S::Error::custom("path is not valid UTF-8"))?;
+            serializer.serialize_str(s)
+        }
+    }
+}
+
+impl<M> SerializeWith<PathBuf> for PathEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn serialize_with<S: Serializer>(value: &PathBuf, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_with(value.as_path(), serializer)
+    }
+}
+
+impl<M> SerializeWith<&Path> for PathEncoding<M>
+where
+    M: Utf8Mode,
+{
+    fn serialize_with<S: Serializer>(value: &&Path, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize_with(*value, serializer)
+    }
+}
+
+impl<'de, M> DeserializeWith<'de, PathBuf> for PathEncoding<M> {
+    fn deserialize_with<D>(deserializer: D) -> Result<PathBuf, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(PathBuf::from)
+    }
+}
+
+impl<'de, M> DeserializeWith<'de, &'de Path> for PathEncoding<M> {
+    fn deserialize_with<D>(deserializer: D) -> Result<&'de Path, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <&str>::deserialize(deserializer).map(Path::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+    };
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::PathUtf8")] PathBuf);
+
+    #[test]
+    fn path_roundtrips_as_string() {
+        check_serialization(Foo("dir/file.txt".into()), json!("dir/file.txt"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Borrowed<'a>(#[serde(borrow, with = "sa::PathUtf8")] &'a Path);
+
+    #[test]
+    fn path_can_be_borrowed() {
+        let deserialized = serde_json::from_str::<Borrowed<'_>>(r#""a/b""#).unwrap();
+        assert_eq!(deserialized, Borrowed(Path::new("a/b")));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Sizes(#[serde(with = "sa::Map::<sa::PathUtf8, sa::Id>")] HashMap<PathBuf, u64>);
+
+    #[test]
+    fn paths_can_be_map_keys() {
+        check_serialization(Sizes(HashMap::from([("a".into(), 1)])), json!({ "a": 1 }));
+    }
+
+    #[cfg(unix)]
+    fn non_utf8_path() -> PathBuf {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+        OsStr::from_bytes(b"a\xffb").into()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn strict_mode_rejects_non_utf8_path() {
+        serde_json::to_value(Foo(non_utf8_path())).unwrap_err();
+    }
+
+    #[cfg(unix)]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Lossy(#[serde(with = "sa::LossyPathUtf8")] PathBuf);
+
+    #[cfg(unix)]
+    #[test]
+    fn lossy_mode_replaces_invalid_sequences() {
+        let serialized = serde_json::to_value(Lossy(non_utf8_path())).unwrap();
+        assert_eq!(serialized, json!("a\u{fffd}b"));
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{
+    content::{Content, ContentRefDeserializer},
+    DeserializeWith, SerializeWith,
+};
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+/// Adapter to deserialize with the first adapter that succeeds
+///
+/// `L` is a tuple of adapters. When deserializing, the input is buffered and each adapter is tried
+/// in order until one succeeds. When serializing, the first adapter in the tuple is used.
+///
+/// Buffering the input requires a self-describing format. Tuples of up to 8 adapters are
+/// supported.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::PickFirst::<(sa::Str, sa::Id)>")] u32);
+///
+/// let foo = serde_json::from_value::<Foo>(json!("33")).unwrap();
+/// assert_eq!(foo, Foo(33));
+/// let foo = serde_json::from_value::<Foo>(json!(33)).unwrap();
+/// assert_eq!(foo, Foo(33));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("33"));
+/// ```
+pub struct PickFirst<L>(PhantomData<L>);
+
+impl<L> PickFirst<L> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+macro_rules! impl_pick_first {
+    ($(($first:ident $(, $rest:ident)*),)*) => {
+        $(
+            impl<$first, $($rest,)* T> SerializeWith<T> for PickFirst<($first, $($rest,)*)>
+            where
+                $first: SerializeWith<T>,
+                T: ?Sized,
+            {
+                fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+                    $first::serialize_with(value, serializer)
+                }
+            }
+
+            impl<'de, $first, $($rest,)* T> DeserializeWith<'de, T>
+                for PickFirst<($first, $($rest,)*)>
+            where
+                $first: DeserializeWith<'de, T>,
+                $($rest: DeserializeWith<'de, T>,)*
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let human_readable = deserializer.is_human_readable();
+                    let content = Content::deserialize(deserializer)?;
+                    let new_deserializer =
+                        || ContentRefDeserializer::<D::Error>::new(&content, human_readable);
+                    if let Ok(value) = $first::deserialize_with(new_deserializer()) {
+                        return Ok(value);
+                    }
+                    $(
+                        if let Ok(value) = $rest::deserialize_with(new_deserializer()) {
+                            return Ok(value);
+                        }
+                    )*
+                    Err(D::Error::custom("data did not match any adapter"))
+                }
+            }
+        )*
+    };
+}
+
+impl_pick_first! {
+    (F0),
+    (F0, F1),
+    (F0, F1, F2),
+    (F0, F1, F2, F3),
+    (F0, F1, F2, F3, F4),
+    (F0, F1, F2, F3, F4, F5),
+    (F0, F1, F2, F3, F4, F5, F6),
+    (F0, F1, F2, F3, F4, F5, F6, F7),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Number(#[serde(with = "sa::PickFirst::<(sa::Str, sa::Id)>")] u32);
+
+    #[test]
+    fn first_adapter_is_used_to_serialize() {
+        check_serialization(Number(33), json!("33"));
+    }
+
+    #[test]
+    fn second_adapter_is_tried_if_first_fails() {
+        let deserialized = serde_json::from_value::<Number>(json!(33)).unwrap();
+        assert_eq!(deserialized, Number(33));
+    }
+
+    #[test]
+    fn no_matching_adapter_fails() {
+        serde_json::from_value::<Number>(json!([33])).unwrap_err();
+        serde_json::from_value::<Number>(json!("x")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Bytes(#[serde(with = "sa::PickFirst::<(sa::Hex, sa::Seq<sa::Str>, sa::Id)>")] Vec<u8>);
+
+    #[test]
+    fn adapters_are_tried_in_order() {
+        let deserialized = serde_json::from_value::<Bytes>(json!("0a0b")).unwrap();
+        assert_eq!(deserialized, Bytes(vec![10, 11]));
+        let deserialized = serde_json::from_value::<Bytes>(json!(["10", "11"])).unwrap();
+        assert_eq!(deserialized, Bytes(vec![10, 11]));
+        let deserialized = serde_json::from_value::<Bytes>(json!([10, 11])).unwrap();
+        assert_eq!(deserialized, Bytes(vec![10, 11]));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Nested(#[serde(with = "sa::PickFirst::<(sa::Option<sa::Str>,)>")] Option<u32>);
+
+    #[test]
+    fn options_are_buffered() {
+        check_serialization(Nested(Some(3)), json!("3"));
+        check_serialization(Nested(None), json!(null));
+    }
+}
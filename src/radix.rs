@@ -0,0 +1,287 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{int_parts::IntParts, DeserializeWith, SerializeWith};
+use core::fmt::{self, Display, Write};
+use serde::{de::Visitor, ser::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize integers as strings of digits in radix `R`
+///
+/// `R` must be between 2 and 36 inclusive. Digits above 9 are serialized as lowercase letters.
+///
+/// If `PREFIX` is `true`, the digits are preceded by `0b`, `0o` or `0x` for radixes 2, 8 and 16
+/// respectively. Other radixes have no prefix and cannot be used with `PREFIX` set. When
+/// deserializing, the prefix matching `R` is optional regardless of `PREFIX`. Negative numbers
+/// start with a `-` sign, followed by the prefix if any.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::Radix::<16, true>")]
+///     flags: u32,
+///     #[serde(with = "sa::Radix::<2>")]
+///     mask: i8,
+/// }
+///
+/// let foo = Foo { flags: 0xff, mask: -5 };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "flags": "0xff", "mask": "-101" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct Radix<const R: u32, const PREFIX: bool = false>;
+
+impl<const R: u32, const PREFIX: bool> Radix<R, PREFIX> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+macro_rules! impl_radix {
+    ($($t:ty),*) => {
+        $(
+            impl<const R: u32, const PREFIX: bool> SerializeWith<$t> for Radix<R, PREFIX> {
+                fn serialize_with<S: Serializer>(
+                    &value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let (negative, magnitude) = value.into_parts();
+                    serialize_parts::<R, PREFIX, S>(negative, magnitude, serializer)
+                }
+            }
+
+            impl<'de, const R: u32, const PREFIX: bool> DeserializeWith<'de, $t>
+                for Radix<R, PREFIX>
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let (negative, magnitude) =
+                        deserializer.deserialize_str(RadixVisitor::<R>::new(<$t>::SIGNED))?;
+                    <$t>::from_parts(negative, magnitude)
+                        .ok_or_else(|| serde::de::Error::custom("number out of range"))
+                }
+            }
+        )*
+    };
+}
+
+impl_radix!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+fn prefix(radix: u32) -> Option<&'static str> {
+    match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    }
+}
+
+fn check_radix(radix: u32) -> Result<(), &'static str> {
+    if (2..=36).contains(&radix) {
+        Ok(())
+    } else {
+        Err("radix must be between 2 and 36")
+    }
+}
+
+fn serialize_parts<const R: u32, const PREFIX: bool, S>(
+    negative: bool,
+    magnitude: u128,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    check_radix(R).map_err(S::Error::custom)?;
+    let prefix = match (PREFIX, prefix(R)) {
+        (false, _) => "",
+        (true, Some(prefix)) => prefix,
+        (true, None) => return Err(S::Error::custom(format_args!("no prefix for radix {R}"))),
+    };
+    serializer.collect_str(&RadixDisplay {
+        radix: R,
+        prefix,
+        negative,
+        magnitude,
+    })
+}
+
+struct RadixDisplay {
+    radix: u32,
+    prefix: &'static str,
+    negative: bool,
+    magnitude: u128,
+}
+
+impl Display for RadixDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Enough for a 128-bit number in radix 2
+        let mut digits = [0u8; 128];
+        let mut start = digits.len();
+        let mut n = self.magnitude;
+        let radix = u128::from(self.radix);
+        loop {
+            start -= 1;
+            digits[start] = b"0123456789abcdefghijklmnopqrstuvwxyz"[(n % radix) as usize];
+            n /= radix;
+            if n == 0 {
+                break;
+            }
+        }
+        if self.negative {
+            f.write_char('-')?;
+        }
+        f.write_str(self.prefix)?;
+        digits[start..]
+            .iter()
+            .try_for_each(|&d| f.write_char(d.into()))
+    }
+}
+
+struct RadixVisitor<const R: u32> {
+    signed: bool,
+}
+
+impl<const R: u32> RadixVisitor<R> {
+    fn new(signed: bool) -> Self {
+        Self { signed }
+    }
+}
+
+impl<'de, const R: u32> Visitor<'de> for RadixVisitor<R> {
+    type Value = (bool, u128);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string of digits in radix {R}")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        check_radix(R).map_err(E::custom)?;
+        let (negative, digits) = match v.strip_prefix('-') {
+            Some(digits) if self.signed => (true, digits),
+            _ => (false, v),
+        };
+        let digits = prefix(R)
+            .and_then(|prefix| digits.strip_prefix(prefix))
+            .unwrap_or(digits);
+        if digits.starts_with(['+', '-']) {
+            return Err(E::invalid_value(serde::de::Unexpected::Str(v), &self));
+        }
+        let magnitude = u128::from_str_radix(digits, R).map_err(E::custom)?;
+        Ok((negative, magnitude))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Hex(#[serde(with = "crate::Radix::<16>")] u32);
+
+    #[test]
+    fn hex_radix_roundtrips() {
+        check_serialization(Hex(0xff), json!("ff"));
+    }
+
+    #[test]
+    fn radix_accepts_prefix() {
+        let v = serde_json::from_value::<Hex>(json!("0xFF")).unwrap();
+        assert_eq!(v, Hex(0xff));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct PrefixedOctal(#[serde(with = "crate::Radix::<8, true>")] i64);
+
+    #[test]
+    fn prefixed_octal_radix_roundtrips() {
+        check_serialization(PrefixedOctal(8), json!("0o10"));
+        check_serialization(PrefixedOctal(-8), json!("-0o10"));
+        check_serialization(PrefixedOctal(0), json!("0o0"));
+    }
+
+    #[test]
+    fn prefixed_radix_accepts_bare_digits() {
+        let v = serde_json::from_value::<PrefixedOctal>(json!("-17")).unwrap();
+        assert_eq!(v, PrefixedOctal(-0o17));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Binary(#[serde(with = "crate::Radix::<2>")] i8);
+
+    #[test]
+    fn binary_radix_roundtrips_extremes() {
+        check_serialization(Binary(i8::MIN), json!("-10000000"));
+        check_serialization(Binary(i8::MAX), json!("1111111"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Base36(#[serde(with = "crate::Radix::<36>")] u128);
+
+    #[test]
+    fn base36_radix_roundtrips_max() {
+        check_serialization(Base36(u128::MAX), json!("f5lxx1zz5pnorynqglhzmsp33"));
+    }
+
+    #[test]
+    fn invalid_radix_digit_fails() {
+        serde_json::from_value::<Hex>(json!("fg")).unwrap_err();
+    }
+
+    #[test]
+    fn negative_unsigned_radix_fails() {
+        serde_json::from_value::<Hex>(json!("-1")).unwrap_err();
+    }
+
+    #[test]
+    fn out_of_range_radix_fails() {
+        serde_json::from_value::<Hex>(json!("1ffffffff")).unwrap_err();
+    }
+
+    #[test]
+    fn double_sign_radix_fails() {
+        serde_json::from_value::<Hex>(json!("+-1")).unwrap_err();
+        serde_json::from_value::<PrefixedOctal>(json!("-+1")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct PrefixedBase36(#[serde(with = "crate::Radix::<36, true>")] u32);
+
+    #[test]
+    fn prefix_without_radix_prefix_fails() {
+        serde_json::to_value(PrefixedBase36(1)).unwrap_err();
+    }
+}
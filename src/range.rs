@@ -2,10 +2,11 @@
 
 use crate::{DeserializeWith, SerializeWith, WithEncoding};
 use core::{
+    fmt,
     marker::PhantomData,
-    ops::{Bound, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
+    ops::{Bound, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
 };
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Adapter for range-related types
 ///
@@ -225,10 +226,44 @@ where
     }
 }
 
+impl<F> SerializeWith<RangeFull> for Range<F> {
+    fn serialize_with<S: Serializer>(_: &RangeFull, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("RangeFull")
+    }
+}
+
+impl<'de, F> DeserializeWith<'de, RangeFull> for Range<F> {
+    fn deserialize_with<D>(deserializer: D) -> Result<RangeFull, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_unit_struct("RangeFull", RangeFullVisitor)
+    }
+}
+
+struct RangeFullVisitor;
+
+impl<'de> Visitor<'de> for RangeFullVisitor {
+    type Value = RangeFull;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unit")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(..)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::check_serialization;
-    use core::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+    use core::ops::{
+        Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    };
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
@@ -284,4 +319,18 @@ mod tests {
     fn range_adapter_works_for_bound() {
         check_serialization(WrapBound(Bound::Included(3)), json!({ "Included": "3" }));
     }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct WrapRangeFull(#[serde(with = "crate::Range::<crate::Str>")] RangeFull);
+
+    #[test]
+    fn range_adapter_works_for_range_full() {
+        check_serialization(WrapRangeFull(..), json!(null));
+        let serialized = bincode::serialize(&WrapRangeFull(..)).unwrap();
+        assert!(serialized.is_empty());
+        assert_eq!(
+            bincode::deserialize::<WrapRangeFull>(&serialized).unwrap(),
+            WrapRangeFull(..)
+        );
+    }
 }
@@ -0,0 +1,224 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SumOp, SumOverflow, Summand, WithEncoding};
+use core::{convert::Infallible, fmt, marker::PhantomData};
+use serde::{
+    de::{Error as _, SeqAccess, Visitor},
+    Deserializer,
+};
+
+/// Adapter to deserialize a non-empty sequence by reducing its items with `Op`
+///
+/// Items are deserialized with `F`. The first item is the initial accumulator, and following items
+/// are combined with it as they are read, so the sequence is never collected. Unlike
+/// [`FoldWith`](crate::FoldWith), no initial value is needed. Deserializing an empty sequence fails
+/// with an [`invalid_length`](serde::de::Error::invalid_length) error, and deserialization also
+/// fails if combining items fails.
+///
+/// This adapter can only be used for deserialization, as a reduced value cannot be turned back
+/// into the original sequence.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Foo(#[serde(with = "sa::ReduceWith::<sa::MaxOp, sa::Str>")] u32);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!(["3", "8", "5"])).unwrap();
+/// assert_eq!(deserialized, Foo(8));
+/// assert!(serde_json::from_value::<Foo>(json!([])).is_err());
+/// ```
+pub struct ReduceWith<Op, F = crate::Id>(PhantomData<(Op, F)>);
+
+/// Adapter to deserialize a non-empty sequence as the sum of its items
+///
+/// See [`ReduceWith`] and [`SumOp`].
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Foo(#[serde(with = "sa::Reduce::<sa::Id>")] u32);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!([1, 2, 3])).unwrap();
+/// assert_eq!(deserialized, Foo(6));
+/// ```
+pub type Reduce<F = crate::Id> = ReduceWith<SumOp, F>;
+
+impl<Op, F> ReduceWith<Op, F> {
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Reducing operation used by [`ReduceWith`]
+pub trait ReduceOp<A> {
+    /// Error combining the accumulator with an item
+    type Error: fmt::Display;
+
+    /// Combines the accumulator with an item
+    fn combine(acc: A, item: A) -> Result<A, Self::Error>;
+}
+
+impl<A> ReduceOp<A> for SumOp
+where
+    A: Summand,
+{
+    type Error = SumOverflow;
+
+    fn combine(acc: A, item: A) -> Result<A, SumOverflow> {
+        acc.checked_add(item).ok_or(SumOverflow)
+    }
+}
+
+/// Reducing operation keeping the smallest item
+///
+/// The first smallest item is kept if several items are equal.
+pub struct MinOp;
+
+impl<A> ReduceOp<A> for MinOp
+where
+    A: Ord,
+{
+    type Error = Infallible;
+
+    fn combine(acc: A, item: A) -> Result<A, Infallible> {
+        Ok(acc.min(item))
+    }
+}
+
+/// Reducing operation keeping the largest item
+///
+/// The last largest item is kept if several items are equal.
+pub struct MaxOp;
+
+impl<A> ReduceOp<A> for MaxOp
+where
+    A: Ord,
+{
+    type Error = Infallible;
+
+    fn combine(acc: A, item: A) -> Result<A, Infallible> {
+        Ok(acc.max(item))
+    }
+}
+
+impl<'de, Op, F, A> DeserializeWith<'de, A> for ReduceWith<Op, F>
+where
+    Op: ReduceOp<A>,
+    F: DeserializeWith<'de, A>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<A, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReduceVisitor::<Op, F, A>::new())
+    }
+}
+
+struct ReduceVisitor<Op, F, A> {
+    _adapter: PhantomData<(Op, F)>,
+    _acc: PhantomData<fn() -> A>,
+}
+
+impl<Op, F, A> ReduceVisitor<Op, F, A> {
+    fn new() -> Self {
+        Self {
+            _adapter: PhantomData,
+            _acc: PhantomData,
+        }
+    }
+}
+
+impl<'de, Op, F, A> Visitor<'de> for ReduceVisitor<Op, F, A>
+where
+    Op: ReduceOp<A>,
+    F: DeserializeWith<'de, A>,
+{
+    type Value = A;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a non-empty sequence")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let mut acc = seq
+            .next_element::<WithEncoding<F, A>>()?
+            .ok_or_else(|| S::Error::invalid_length(0, &self))?
+            .into_inner();
+        while let Some(item) = seq.next_element::<WithEncoding<F, A>>()? {
+            acc = Op::combine(acc, item.into_inner()).map_err(S::Error::custom)?;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as sa;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Total(#[serde(with = "sa::Reduce::<sa::Str>")] i32);
+
+    #[test]
+    fn reduce_sums_items() {
+        let deserialized = serde_json::from_value::<Total>(json!(["1", "-2", "4"])).unwrap();
+        assert_eq!(deserialized, Total(3));
+        let deserialized = serde_json::from_value::<Total>(json!(["5"])).unwrap();
+        assert_eq!(deserialized, Total(5));
+    }
+
+    #[test]
+    fn reduce_fails_on_empty_sequence() {
+        serde_json::from_value::<Total>(json!([])).unwrap_err();
+    }
+
+    #[test]
+    fn reduce_fails_on_overflow() {
+        let e = serde_json::from_value::<Total>(json!(["-2147483648", "-1"])).unwrap_err();
+        assert_eq!(e.to_string(), "sum overflows");
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct NoDefault(u32);
+
+    impl sa::Summand for NoDefault {
+        fn checked_add(self, other: Self) -> Option<Self> {
+            self.0.checked_add(other.0).map(Self)
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Wrapper(#[serde(with = "sa::Reduce::<sa::Id>")] NoDefault);
+
+    #[test]
+    fn reduce_does_not_require_default() {
+        let deserialized = serde_json::from_value::<Wrapper>(json!([1, 2])).unwrap();
+        assert_eq!(deserialized, Wrapper(NoDefault(3)));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Lowest(#[serde(with = "sa::ReduceWith::<sa::MinOp>")] u32);
+
+    #[test]
+    fn reduce_with_min_keeps_smallest_item() {
+        let deserialized = serde_json::from_value::<Lowest>(json!([3, 1, 5])).unwrap();
+        assert_eq!(deserialized, Lowest(1));
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Id, SerializeWith};
+use core::marker::PhantomData;
+use serde::Serializer;
+
+/// Adapter to serialize using a [`From`](core::convert::From) conversion from a borrow
+///
+/// This adapter works by converting a borrow of the value to `T` and then serializing the
+/// converted value using adapter `F`. Unlike [`Into`](crate::Into), the value does not need to be
+/// cloned.
+///
+/// # Example
+/// ```
+/// use serde::Serialize;
+/// use serde_json::json;
+///
+/// #[derive(Serialize)]
+/// struct Len(usize);
+///
+/// impl From<&Vec<u8>> for Len {
+///     fn from(bytes: &Vec<u8>) -> Len {
+///         Len(bytes.len())
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Foo(#[serde(with = "serdapt::RefInto::<Len>")] Vec<u8>);
+///
+/// let v = serde_json::to_value(Foo(vec![1, 2, 3])).unwrap();
+/// assert_eq!(v, json!(3));
+/// ```
+pub struct RefInto<T, F = Id> {
+    _convert: PhantomData<fn() -> T>,
+    _f: PhantomData<F>,
+}
+
+impl<T, F> RefInto<T, F> {
+    /// Serializes value with adapter
+    pub fn serialize<U, S>(value: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<U>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+}
+
+impl<T, U, F> SerializeWith<U> for RefInto<T, F>
+where
+    T: for<'a> From<&'a U>,
+    U: ?Sized,
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&T::from(value), serializer)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Id, SerializeWith};
+use core::{fmt::Display, marker::PhantomData};
+use serde::Serializer;
+
+/// Adapter to serialize using a [`TryFrom`](core::convert::TryFrom) conversion from a borrow
+///
+/// This adapter works by attempting a conversion from a borrow of the value to `T`, and then
+/// serializing the converted value using adapter `F`. Unlike [`TryInto`](crate::TryInto), the
+/// value does not need to be cloned.
+///
+/// # Example
+/// ```
+/// use serde::Serialize;
+/// use serde_json::json;
+///
+/// struct StrNumber(String);
+///
+/// impl TryFrom<&StrNumber> for i32 {
+///     type Error = std::num::ParseIntError;
+///
+///     fn try_from(value: &StrNumber) -> Result<Self, Self::Error> {
+///         value.0.parse()
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Foo {
+///     #[serde(with = "serdapt::RefTryInto::<i32>")]
+///     n: StrNumber,
+/// }
+///
+/// let v = serde_json::to_value(Foo {
+///     n: StrNumber("33".into()),
+/// })
+/// .unwrap();
+/// assert_eq!(v, json!({ "n": 33 }));
+/// ```
+pub struct RefTryInto<T, F = Id> {
+    _convert: PhantomData<fn() -> T>,
+    _f: PhantomData<F>,
+}
+
+impl<T, F> RefTryInto<T, F> {
+    /// Serializes value with adapter
+    pub fn serialize<U, S>(value: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<U>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+}
+
+impl<T, U, F> SerializeWith<U> for RefTryInto<T, F>
+where
+    T: for<'a> TryFrom<&'a U>,
+    for<'a> <T as TryFrom<&'a U>>::Error: Display,
+    U: ?Sized,
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(
+            &T::try_from(value).map_err(serde::ser::Error::custom)?,
+            serializer,
+        )
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use alloc::string::{String, ToString};
+    use serde::Serialize;
+    use serde_json::json;
+
+    struct StrNumber(String);
+
+    impl TryFrom<&StrNumber> for i32 {
+        type Error = core::num::ParseIntError;
+
+        fn try_from(value: &StrNumber) -> Result<Self, Self::Error> {
+            value.0.parse()
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Foo(#[serde(with = "crate::RefTryInto::<i32, crate::Str>")] StrNumber);
+
+    #[test]
+    fn ref_try_into_adapter_converts_from_borrow() {
+        let v = serde_json::to_value(Foo(StrNumber("33".to_string()))).unwrap();
+        assert_eq!(v, json!("33"));
+    }
+
+    #[test]
+    fn ref_try_into_adapter_fails_to_serialize_if_conversion_fails() {
+        serde_json::to_value(Foo(StrNumber("x".to_string()))).unwrap_err();
+    }
+}
@@ -1,8 +1,11 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
 use crate::{DeserializeWith, SerializeWith, WithEncoding};
-use core::marker::PhantomData;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, DeserializeSeed, EnumAccess, Unexpected, VariantAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 /// Adapter for [`Result`](core::result::Result)
 ///
@@ -78,3 +81,263 @@ where
             .map_err(WithEncoding::into_inner))
     }
 }
+
+/// Adapter for [`Result`](core::result::Result) with custom variant names
+///
+/// This behaves like [`Result`] but the names of the `Ok` and `Err` variants are provided by `N`.
+/// `F` is used to serialize the success value and `G` is used to serialize the error value.
+///
+/// Only the externally tagged representation is supported, i.e. the result is serialized as a
+/// single-entry map keyed by the variant name in self-describing formats like JSON. Serde
+/// attributes selecting another enum representation, like `#[serde(tag = "...")]`, only apply to
+/// the enum type they annotate and have no effect on this adapter.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct Outcome;
+///
+/// impl sa::ResultVariants for Outcome {
+///     const OK: &'static str = "success";
+///     const ERR: &'static str = "failure";
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(
+///     #[serde(with = "sa::NamedResult::<Outcome, sa::Str, sa::Id>")] Result<i32, String>,
+/// );
+///
+/// let foo = Foo(Ok(33));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "success": "33" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+///
+/// let foo = Foo(Err("oops".to_owned()));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "failure": "oops" }));
+/// ```
+pub struct NamedResult<N, F = crate::Id, G = crate::Id> {
+    _n: PhantomData<N>,
+    _f: PhantomData<F>,
+    _g: PhantomData<G>,
+}
+
+impl<N, F, G> NamedResult<N, F, G> {
+    crate::adapter_methods!(pub);
+}
+
+/// Names of the variants of a result serialized with [`NamedResult`]
+pub trait ResultVariants {
+    /// Name of the success variant
+    const OK: &'static str;
+    /// Name of the error variant
+    const ERR: &'static str;
+}
+
+struct Variants<N>(PhantomData<N>);
+
+impl<N: ResultVariants> Variants<N> {
+    const NAMES: &'static [&'static str] = &[N::OK, N::ERR];
+}
+
+const RESULT_NAME: &str = "Result";
+
+impl<N, F, G, T, E> SerializeWith<core::result::Result<T, E>> for NamedResult<N, F, G>
+where
+    N: ResultVariants,
+    F: SerializeWith<T>,
+    G: SerializeWith<E>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &core::result::Result<T, E>,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        match value {
+            Ok(x) => serializer.serialize_newtype_variant(
+                RESULT_NAME,
+                0,
+                N::OK,
+                &WithEncoding::<&F, &T>::from(x),
+            ),
+            Err(e) => serializer.serialize_newtype_variant(
+                RESULT_NAME,
+                1,
+                N::ERR,
+                &WithEncoding::<&G, &E>::from(e),
+            ),
+        }
+    }
+}
+
+impl<'de, N, F, G, T, E> DeserializeWith<'de, core::result::Result<T, E>> for NamedResult<N, F, G>
+where
+    N: ResultVariants,
+    F: DeserializeWith<'de, T>,
+    G: DeserializeWith<'de, E>,
+{
+    fn deserialize_with<D>(
+        deserializer: D,
+    ) -> core::result::Result<core::result::Result<T, E>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(
+            RESULT_NAME,
+            Variants::<N>::NAMES,
+            NamedResultVisitor::<N, F, G, T, E>::new(),
+        )
+    }
+}
+
+struct NamedResultVisitor<N, F, G, T, E> {
+    _n: PhantomData<N>,
+    _f: PhantomData<F>,
+    _g: PhantomData<G>,
+    _r: PhantomData<fn() -> core::result::Result<T, E>>,
+}
+
+impl<N, F, G, T, E> NamedResultVisitor<N, F, G, T, E> {
+    fn new() -> Self {
+        NamedResultVisitor {
+            _n: PhantomData,
+            _f: PhantomData,
+            _g: PhantomData,
+            _r: PhantomData,
+        }
+    }
+}
+
+impl<'de, N, F, G, T, E> Visitor<'de> for NamedResultVisitor<N, F, G, T, E>
+where
+    N: ResultVariants,
+    F: DeserializeWith<'de, T>,
+    G: DeserializeWith<'de, E>,
+{
+    type Value = core::result::Result<T, E>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "enum with variants `{}` and `{}`", N::OK, N::ERR)
+    }
+
+    fn visit_enum<A>(self, data: A) -> core::result::Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (is_ok, variant) = data.variant_seed(VariantIsOk::<N>(PhantomData))?;
+        if is_ok {
+            variant
+                .newtype_variant::<WithEncoding<F, T>>()
+                .map(|x| Ok(x.into_inner()))
+        } else {
+            variant
+                .newtype_variant::<WithEncoding<G, E>>()
+                .map(|e| Err(e.into_inner()))
+        }
+    }
+}
+
+struct VariantIsOk<N>(PhantomData<N>);
+
+impl<'de, N> DeserializeSeed<'de> for VariantIsOk<N>
+where
+    N: ResultVariants,
+{
+    type Value = bool;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, N> Visitor<'de> for VariantIsOk<N>
+where
+    N: ResultVariants,
+{
+    type Value = bool;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` or `{}`", N::OK, N::ERR)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> core::result::Result<bool, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            0 => Ok(true),
+            1 => Ok(false),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> core::result::Result<bool, E>
+    where
+        E: de::Error,
+    {
+        if v == N::OK {
+            Ok(true)
+        } else if v == N::ERR {
+            Ok(false)
+        } else {
+            Err(E::unknown_variant(v, Variants::<N>::NAMES))
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> core::result::Result<bool, E>
+    where
+        E: de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    struct Outcome;
+
+    impl sa::ResultVariants for Outcome {
+        const OK: &'static str = "success";
+        const ERR: &'static str = "failure";
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::NamedResult::<Outcome, sa::Id, sa::Str>")] Result<bool, u8>);
+
+    #[test]
+    fn variants_are_renamed() {
+        check_serialization(Foo(Ok(true)), json!({ "success": true }));
+        check_serialization(Foo(Err(3)), json!({ "failure": "3" }));
+    }
+
+    #[test]
+    fn default_variant_names_are_rejected() {
+        let e = serde_json::from_value::<Foo>(json!({ "Ok": true })).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "unknown variant `Ok`, expected `success` or `failure`"
+        );
+    }
+
+    #[test]
+    fn named_result_roundtrips_with_bincode() {
+        for original in [Foo(Ok(false)), Foo(Err(7))] {
+            let serialized = bincode::serialize(&original).unwrap();
+            let deserialized = bincode::deserialize::<Foo>(&serialized).unwrap();
+            assert_eq!(deserialized, original);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+#[cfg(feature = "alloc")]
+use crate::DeserializeWith;
+use crate::{SerializeWith, WithEncoding};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter::Rev, marker::PhantomData};
+#[cfg(feature = "alloc")]
+use serde::Deserializer;
+use serde::Serializer;
+
+/// Adapter to serialize a sequence in reverse order
+///
+/// Items are serialized with `F` from last to first, and the order is reversed again when
+/// deserializing, so the serialized form is reversed compared to the collection. This is useful to
+/// interoperate with formats storing stacks from top to bottom.
+///
+/// Serializing requires iterating the collection by reference with a [`DoubleEndedIterator`], as
+/// expressed by [`RevIntoIter`]. Collections whose iterators are not double-ended are not
+/// supported, rather than being collected into a temporary buffer to be reversed. Deserializing
+/// works with any collection implementing [`FromIterator`] and requires the `alloc` feature, as
+/// items are always buffered in a `Vec` before being collected in reverse order.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Stack(#[serde(with = "sa::RevSeq::<sa::Str>")] Vec<i32>);
+///
+/// let stack = Stack(vec![1, 2, 3]);
+/// let serialized = serde_json::to_value(&stack).unwrap();
+/// assert_eq!(serialized, json!(["3", "2", "1"]));
+/// let deserialized = serde_json::from_value::<Stack>(serialized).unwrap();
+/// assert_eq!(deserialized, stack);
+/// # }
+/// ```
+pub struct RevSeq<F = crate::Id>(PhantomData<F>);
+
+impl<F> RevSeq<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, C, T> SerializeWith<C> for RevSeq<F>
+where
+    F: SerializeWith<T>,
+    C: ?Sized,
+    for<'a> &'a C: RevIntoIter<'a, Item = &'a T>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(container.rev_into_iter().map(WithEncoding::<&F, _>::from))
+    }
+}
+
+/// Collection reference that can be iterated in reverse
+///
+/// This is implemented for references to all collections whose iterator is a
+/// [`DoubleEndedIterator`]. It only exists because bounding such iterators for all lifetimes
+/// directly defeats type inference.
+pub trait RevIntoIter<'a> {
+    /// Item type
+    type Item: 'a;
+    /// Iterator type
+    type IntoIter: Iterator<Item = Self::Item>;
+
+    /// Returns an iterator over the items in reverse order
+    fn rev_into_iter(self) -> Self::IntoIter;
+}
+
+impl<'a, C> RevIntoIter<'a> for &'a C
+where
+    C: ?Sized,
+    &'a C: IntoIterator,
+    <&'a C as IntoIterator>::IntoIter: DoubleEndedIterator,
+    <&'a C as IntoIterator>::Item: 'a,
+{
+    type Item = <&'a C as IntoIterator>::Item;
+    type IntoIter = Rev<<&'a C as IntoIterator>::IntoIter>;
+
+    fn rev_into_iter(self) -> Self::IntoIter {
+        self.into_iter().rev()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, F, C> DeserializeWith<'de, C> for RevSeq<F>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items: Vec<C::Item> = crate::Seq::<F>::deserialize_with(deserializer)?;
+        Ok(items.into_iter().rev().collect())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::{collections::VecDeque, vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Stack(#[serde(with = "sa::RevSeq::<sa::Str>")] Vec<u8>);
+
+    #[test]
+    fn items_are_serialized_in_reverse_order() {
+        check_serialization(Stack(vec![1, 2, 3]), json!(["3", "2", "1"]));
+        check_serialization(Stack(vec![]), json!([]));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Deque(#[serde(with = "sa::RevSeq::<sa::Id>")] VecDeque<char>);
+
+    #[test]
+    fn deque_roundtrips_with_bincode() {
+        let original = Deque(VecDeque::from(['a', 'b', 'c']));
+        let serialized = bincode::serialize(&original).unwrap();
+        let expected = bincode::serialize(&['c', 'b', 'a'].to_vec()).unwrap();
+        assert_eq!(serialized, expected);
+        let deserialized = bincode::deserialize::<Deque>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
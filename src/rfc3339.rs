@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::fmt;
+use serde::{de::Visitor, ser::Error as _, Deserializer, Serializer};
+use std::time::{Duration, SystemTime};
+
+/// Adapter to serialize [`SystemTime`] as an RFC 3339 string such as `"2024-01-02T03:04:05Z"`
+///
+/// Formatting and parsing are done with the [`humantime`] crate. Timestamps are always in UTC.
+/// Fractional seconds are only included when non-zero, with nanosecond precision. Parsing accepts
+/// any number of fractional digits, and requires the `Z` or `+00:00` offset.
+///
+/// Only times from 1970 to year 9999 can be represented. Serializing other times fails.
+///
+/// Combined with [`HumanOr`](crate::HumanOr), timestamps can be readable in configuration files
+/// while remaining compact in binary formats.
+///
+/// This adapter is available with the `humantime` feature.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::time::{Duration, SystemTime};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Event {
+///     #[serde(with = "sa::HumanOr::<sa::Rfc3339, sa::Id>")]
+///     at: SystemTime,
+/// }
+///
+/// let event = Event { at: SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_164_645) };
+/// let serialized = serde_json::to_value(&event).unwrap();
+/// assert_eq!(serialized, json!({ "at": "2024-01-02T03:04:05Z" }));
+/// let deserialized = serde_json::from_value::<Event>(serialized).unwrap();
+/// assert_eq!(deserialized, event);
+/// let serialized = bincode::serialize(&event).unwrap();
+/// assert_eq!(bincode::deserialize::<Event>(&serialized).unwrap(), event);
+/// ```
+pub struct Rfc3339;
+
+impl Rfc3339 {
+    crate::adapter_methods!(pub);
+}
+
+/// Seconds since the Unix epoch at the start of year 10000
+const MAX_SECS: u64 = 253_402_300_800;
+
+impl SerializeWith<SystemTime> for Rfc3339 {
+    fn serialize_with<S: Serializer>(value: &SystemTime, serializer: S) -> Result<S::Ok, S::Error> {
+        match value.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(d) if d < Duration::from_secs(MAX_SECS) => {
+                serializer.collect_str(&humantime::format_rfc3339(*value))
+            }
+            _ => Err(S::Error::custom(
+                "time out of range for RFC 3339 (1970 to 9999)",
+            )),
+        }
+    }
+}
+
+impl<'de> DeserializeWith<'de, SystemTime> for Rfc3339 {
+    fn deserialize_with<D>(deserializer: D) -> Result<SystemTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+}
+
+struct Rfc3339Visitor;
+
+impl Visitor<'_> for Rfc3339Visitor {
+    type Value = SystemTime;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an RFC 3339 timestamp such as \"2024-01-02T03:04:05Z\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        humantime::parse_rfc3339(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::time::{Duration, SystemTime};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Rfc3339")] SystemTime);
+
+    #[test]
+    fn time_roundtrips_as_string() {
+        let t = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_164_645);
+        check_serialization(Foo(t), json!("2024-01-02T03:04:05Z"));
+        check_serialization(
+            Foo(t + Duration::from_millis(250)),
+            json!("2024-01-02T03:04:05.250000000Z"),
+        );
+        check_serialization(Foo(SystemTime::UNIX_EPOCH), json!("1970-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn utc_offset_is_accepted() {
+        let t = serde_json::from_value::<Foo>(json!("2024-01-02T03:04:05.25+00:00")).unwrap();
+        let expected = SystemTime::UNIX_EPOCH + Duration::from_millis(1_704_164_645_250);
+        assert_eq!(t, Foo(expected));
+    }
+
+    #[test]
+    fn invalid_timestamp_is_rejected() {
+        serde_json::from_value::<Foo>(json!("2024-01-02")).unwrap_err();
+        serde_json::from_value::<Foo>(json!("2024-01-02T03:04:05+01:00")).unwrap_err();
+        serde_json::from_value::<Foo>(json!(1_704_164_645)).unwrap_err();
+    }
+
+    #[test]
+    fn time_before_epoch_cannot_be_serialized() {
+        let t = SystemTime::UNIX_EPOCH - Duration::from_secs(1);
+        serde_json::to_value(Foo(t)).unwrap_err();
+    }
+}
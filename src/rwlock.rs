@@ -1,11 +1,16 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
-use crate::{DeserializeWith, SerializeWith};
+use crate::{DeserializeWith, LockMode, SerializeWith};
 use core::marker::PhantomData;
 use serde::{ser::Error, Deserializer, Serializer};
 
 /// Adapter for [`RwLock`](std::sync::RwLock)
 ///
+/// Serialization fails if the lock is poisoned. With the default mode
+/// [`NonBlockingLock`](crate::NonBlockingLock), serialization also fails if the lock is held for
+/// writing. With [`BlockingLock`](crate::BlockingLock), serialization waits for the lock to be
+/// released by writers, which deadlocks if the current thread holds it.
+///
 /// # Example
 /// ```
 /// use serdapt as sa;
@@ -19,9 +24,9 @@ use serde::{ser::Error, Deserializer, Serializer};
 /// let v = serde_json::to_value(Foo(33.into())).unwrap();
 /// assert_eq!(v, json!("33"));
 /// ```
-pub struct RwLock<F>(PhantomData<F>);
+pub struct RwLock<F, M = crate::NonBlockingLock>(PhantomData<(F, M)>);
 
-impl<F> RwLock<F> {
+impl<F, M> RwLock<F, M> {
     /// Serializes value with adapter
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -42,20 +47,26 @@ impl<F> RwLock<F> {
     }
 }
 
-impl<F, T> SerializeWith<std::sync::RwLock<T>> for RwLock<F>
+impl<F, M, T> SerializeWith<std::sync::RwLock<T>> for RwLock<F, M>
 where
     F: SerializeWith<T>,
+    M: LockMode,
     T: ?Sized,
 {
     fn serialize_with<S: Serializer>(
         value: &std::sync::RwLock<T>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        F::serialize_with(&*value.try_read().map_err(S::Error::custom)?, serializer)
+        let guard = if M::BLOCKING {
+            value.read().map_err(S::Error::custom)?
+        } else {
+            value.try_read().map_err(S::Error::custom)?
+        };
+        F::serialize_with(&*guard, serializer)
     }
 }
 
-impl<'de, F, T> DeserializeWith<'de, std::sync::RwLock<T>> for RwLock<F>
+impl<'de, F, M, T> DeserializeWith<'de, std::sync::RwLock<T>> for RwLock<F, M>
 where
     F: DeserializeWith<'de, T>,
 {
@@ -91,4 +102,21 @@ mod tests {
         let _lock = foo.0.write().unwrap();
         serde_json::to_value(&foo).unwrap_err();
     }
+
+    #[derive(Deserialize, Serialize)]
+    struct BlockingFoo(
+        #[serde(with = "crate::RwLock::<crate::Str, crate::BlockingLock>")] RwLock<i32>,
+    );
+
+    #[test]
+    fn blocking_rwlock_waits_for_writer() {
+        let foo = BlockingFoo(RwLock::new(33));
+        let lock = foo.0.write().unwrap();
+        std::thread::scope(|scope| {
+            let serialized = scope.spawn(|| serde_json::to_value(&foo).unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            drop(lock);
+            assert_eq!(serialized.join().unwrap(), json!("33"));
+        });
+    }
 }
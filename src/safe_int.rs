@@ -0,0 +1,216 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Unexpected, Visitor},
+    Deserializer, Serialize, Serializer,
+};
+
+/// Adapter to serialize integers as strings when they cannot be represented exactly by a
+/// JavaScript number
+///
+/// See [`SafeIntEncoding`] for details.
+pub type SafeInt = SafeIntEncoding<JsSafe>;
+
+/// Adapter to serialize integers as strings if their magnitude exceeds a limit
+///
+/// Integers whose absolute value is at most `L::MAX` are serialized as numbers, and other integers
+/// are serialized as strings. When deserializing, both numbers and strings are accepted regardless
+/// of the magnitude. This requires a self-describing format.
+///
+/// [`JsSafe`] sets the limit to the largest integer a JavaScript number can represent exactly,
+/// i.e. 2<sup>53</sup> - 1. The [`SafeInt`] alias uses this limit.
+///
+/// All primitive integer types are supported.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Seq::<sa::SafeInt>")] Vec<i64>);
+///
+/// let foo = Foo(vec![-3, 1i64 << 53, -(1i64 << 53) + 1]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([-3, "9007199254740992", -9007199254740991i64]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// let deserialized = serde_json::from_value::<Foo>(json!(["-3", 4])).unwrap();
+/// assert_eq!(deserialized, Foo(vec![-3, 4]));
+/// ```
+pub struct SafeIntEncoding<L>(PhantomData<L>);
+
+impl<L> SafeIntEncoding<L> {
+    crate::adapter_methods!(pub);
+}
+
+/// Largest magnitude of integers serialized as numbers by [`SafeIntEncoding`]
+pub trait SafeIntLimit {
+    /// Largest magnitude serialized as a number
+    const MAX: u128;
+}
+
+/// Limit of integers that a JavaScript number can represent exactly, i.e. 2<sup>53</sup> - 1
+pub struct JsSafe;
+
+impl SafeIntLimit for JsSafe {
+    const MAX: u128 = (1 << 53) - 1;
+}
+
+struct SafeIntVisitor<T>(PhantomData<fn() -> T>);
+
+impl<T> SafeIntVisitor<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+macro_rules! impl_safe_int {
+    ($($ty:ty => $magnitude:expr),*) => {
+        $(
+            impl<L> SerializeWith<$ty> for SafeIntEncoding<L>
+            where
+                L: SafeIntLimit,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$ty,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let magnitude: fn($ty) -> u128 = $magnitude;
+                    if magnitude(*value) <= L::MAX {
+                        value.serialize(serializer)
+                    } else {
+                        serializer.collect_str(value)
+                    }
+                }
+            }
+
+            impl<'de, L> DeserializeWith<'de, $ty> for SafeIntEncoding<L> {
+                fn deserialize_with<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializer.deserialize_any(SafeIntVisitor::<$ty>::new())
+                }
+            }
+
+            impl Visitor<'_> for SafeIntVisitor<$ty> {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(concat!("a ", stringify!($ty), " as a number or a string"))
+                }
+
+                fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    v.try_into()
+                        .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+                }
+
+                fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    v.try_into()
+                        .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+                }
+
+                fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    v.try_into()
+                        .map_err(|_| E::invalid_value(Unexpected::Other("128-bit integer"), &self))
+                }
+
+                fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    v.try_into()
+                        .map_err(|_| E::invalid_value(Unexpected::Other("128-bit integer"), &self))
+                }
+
+                fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    v.parse().map_err(E::custom)
+                }
+            }
+        )*
+    };
+}
+
+impl_safe_int!(
+    u8 => u128::from,
+    u16 => u128::from,
+    u32 => u128::from,
+    u64 => u128::from,
+    u128 => |n| n,
+    usize => |n| n as u128,
+    i8 => |n| n.unsigned_abs().into(),
+    i16 => |n| n.unsigned_abs().into(),
+    i32 => |n| n.unsigned_abs().into(),
+    i64 => |n| n.unsigned_abs().into(),
+    i128 => i128::unsigned_abs,
+    isize => |n| n.unsigned_abs() as u128
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::SafeInt")] u64);
+
+    #[test]
+    fn small_integer_is_a_number() {
+        check_serialization(Foo(0), json!(0));
+        check_serialization(Foo((1 << 53) - 1), json!(9007199254740991u64));
+    }
+
+    #[test]
+    fn large_integer_is_a_string() {
+        check_serialization(Foo(1 << 53), json!("9007199254740992"));
+        check_serialization(Foo(u64::MAX), json!("18446744073709551615"));
+    }
+
+    #[test]
+    fn number_and_string_are_accepted() {
+        let deserialized = serde_json::from_value::<Foo>(json!("12")).unwrap();
+        assert_eq!(deserialized, Foo(12));
+        let deserialized = serde_json::from_value::<Foo>(json!(1u64 << 60)).unwrap();
+        assert_eq!(deserialized, Foo(1 << 60));
+    }
+
+    #[test]
+    fn out_of_range_integer_is_rejected() {
+        serde_json::from_value::<Foo>(json!(-1)).unwrap_err();
+        serde_json::from_value::<Foo>(json!("-1")).unwrap_err();
+        serde_json::from_value::<Foo>(json!(1.5)).unwrap_err();
+    }
+
+    struct Byte;
+
+    impl sa::SafeIntLimit for Byte {
+        const MAX: u128 = 0xff;
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Small(#[serde(with = "sa::SafeIntEncoding::<Byte>")] i16);
+
+    #[test]
+    fn limit_can_be_customized() {
+        check_serialization(Small(-255), json!(-255));
+        check_serialization(Small(-256), json!("-256"));
+        check_serialization(Small(i16::MIN), json!("-32768"));
+    }
+}
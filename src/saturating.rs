@@ -0,0 +1,86 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serialize, Serializer};
+
+/// Adapter for [`Saturating`](core::num::Saturating)
+///
+/// The inner value is deserialized with `F` as is. Unlike the `serde` implementation for
+/// primitive integers, out-of-range values are not clamped.
+///
+/// # Example
+/// ```
+/// use core::num::Saturating;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::Saturating::<serdapt::Str>")] Saturating<i32>);
+///
+/// let v = serde_json::to_value(Foo(Saturating(33))).unwrap();
+/// assert_eq!(v, json!("33"));
+/// ```
+pub struct Saturating<F>(PhantomData<F>);
+
+impl<F> Saturating<F> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<F, T> SerializeWith<core::num::Saturating<T>> for Saturating<F>
+where
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(
+        value: &core::num::Saturating<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = core::num::Saturating(WithEncoding::<&F, &T>::from(&value.0));
+        Serialize::serialize(&value, serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, core::num::Saturating<T>> for Saturating<F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<core::num::Saturating<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(core::num::Saturating)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use core::num::Saturating;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::Saturating::<crate::Str>")] Saturating<i32>);
+
+    #[test]
+    fn saturating_adapter_roundtrips() {
+        check_serialization(Foo(Saturating(33)), json!("33"));
+    }
+}
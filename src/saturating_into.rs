@@ -0,0 +1,120 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{Id, SerializeWith};
+use core::marker::PhantomData;
+use serde::Serializer;
+
+/// Adapter to serialize using a saturating integer conversion
+///
+/// This adapter converts the value to `T` with [`SaturatingFrom`], and then serializes the
+/// converted value using adapter `F`. Unlike [`TryInto`](crate::TryInto), which fails when the
+/// value does not fit in `T`, out-of-range values are clamped to `T::MIN` or `T::MAX`.
+///
+/// This loses data silently: the original value cannot be recovered from the serialized one, and
+/// a serialized `T::MAX` or `T::MIN` may stand for any value beyond it. This is meant for exports
+/// where approximate values are preferable to failures, e.g. downsampling counters.
+///
+/// Conversions between all primitive integer types are supported.
+///
+/// # Example
+/// ```
+/// use serde::Serialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Serialize)]
+/// struct Stats {
+///     #[serde(with = "serdapt::SaturatingInto::<u32>")]
+///     count: u64,
+///     #[serde(with = "serdapt::SaturatingInto::<u8>")]
+///     delta: i32,
+/// }
+///
+/// let v = serde_json::to_value(Stats { count: 1 << 40, delta: -3 }).unwrap();
+/// assert_eq!(v, json!({ "count": u32::MAX, "delta": 0 }));
+/// ```
+pub struct SaturatingInto<T, F = Id> {
+    _convert: PhantomData<fn() -> T>,
+    _f: PhantomData<F>,
+}
+
+impl<T, F> SaturatingInto<T, F> {
+    /// Serializes value with adapter
+    pub fn serialize<U, S>(value: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        U: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<U>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+}
+
+/// Conversion clamping values that do not fit in the target type
+pub trait SaturatingFrom<U> {
+    /// Converts `value`, clamping it to the bounds of `Self` if it is out of range
+    fn saturating_from(value: U) -> Self;
+}
+
+macro_rules! impl_saturating_from {
+    (@from $src:ty => $($dst:ty),*) => {
+        $(
+            impl SaturatingFrom<$src> for $dst {
+                fn saturating_from(value: $src) -> Self {
+                    let bound = if value > 0 { <$dst>::MAX } else { <$dst>::MIN };
+                    <$dst>::try_from(value).unwrap_or(bound)
+                }
+            }
+        )*
+    };
+    ($($src:ty),*) => {
+        $(
+            impl_saturating_from!(
+                @from $src => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+            );
+        )*
+    };
+}
+
+impl_saturating_from!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T, U, F> SerializeWith<U> for SaturatingInto<T, F>
+where
+    T: SaturatingFrom<U>,
+    U: Clone,
+    F: SerializeWith<T>,
+{
+    fn serialize_with<S: Serializer>(value: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(&T::saturating_from(value.clone()), serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, SaturatingFrom};
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Debug, Serialize)]
+    struct Counter(#[serde(with = "sa::SaturatingInto::<u32, sa::Str>")] u64);
+
+    #[test]
+    fn huge_value_saturates_to_max() {
+        let v = serde_json::to_value(Counter(u64::MAX)).unwrap();
+        assert_eq!(v, json!(u32::MAX.to_string()));
+    }
+
+    #[test]
+    fn value_in_range_is_preserved() {
+        let v = serde_json::to_value(Counter(33)).unwrap();
+        assert_eq!(v, json!("33"));
+    }
+
+    #[test]
+    fn conversions_clamp_to_bounds() {
+        assert_eq!(i8::saturating_from(-1000i32), i8::MIN);
+        assert_eq!(i8::saturating_from(1000u16), i8::MAX);
+        assert_eq!(u16::saturating_from(-1i64), 0);
+        assert_eq!(i128::saturating_from(u128::MAX), i128::MAX);
+        assert_eq!(u8::saturating_from(200u64), 200);
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{de::Error as _, ser::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize a number multiplied by the factor `N`
+///
+/// The number is multiplied by `N` and the product is serialized with `F`. When deserializing, the
+/// number deserialized with `F` is divided by `N`. This is useful when the stored unit differs from
+/// the in-memory unit, e.g. to store a ratio as basis points with `N = 10000`.
+///
+/// - For integers, overflowing when multiplying results in an error. Division is exact: a
+///   deserialized number that is not a multiple of `N` results in an error rather than being
+///   rounded. `N` must fit in the integer type.
+/// - For floats, multiplication and division round to the nearest representable value, so a value
+///   may not roundtrip exactly, e.g. if the product is not exactly representable. Non-finite values
+///   are scaled like any other value.
+///
+/// `N` must not be zero.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Fee {
+///     #[serde(with = "sa::Scale::<10000>")]
+///     rate: f64,
+///     #[serde(with = "sa::Scale::<100, sa::Str>")]
+///     amount: u32,
+/// }
+///
+/// let fee = Fee { rate: 0.25, amount: 12 };
+/// let serialized = serde_json::to_value(&fee).unwrap();
+/// assert_eq!(serialized, json!({ "rate": 2500.0, "amount": "1200" }));
+/// let deserialized = serde_json::from_value::<Fee>(serialized).unwrap();
+/// assert_eq!(deserialized, fee);
+/// ```
+pub struct Scale<const N: u64, F = crate::Id>(PhantomData<F>);
+
+impl<const N: u64, F> Scale<N, F> {
+    crate::adapter_methods!(pub);
+}
+
+const ZERO_FACTOR: &str = "scale factor must not be zero";
+
+macro_rules! impl_scale_int {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: u64, F> SerializeWith<$t> for Scale<N, F>
+            where
+                F: SerializeWith<$t>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let factor = int_factor::<N, $t>().map_err(S::Error::custom)?;
+                    let scaled = value.checked_mul(factor).ok_or_else(|| {
+                        S::Error::custom(format_args!("{value} scaled by {N} overflows"))
+                    })?;
+                    F::serialize_with(&scaled, serializer)
+                }
+            }
+
+            impl<'de, const N: u64, F> DeserializeWith<'de, $t> for Scale<N, F>
+            where
+                F: DeserializeWith<'de, $t>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let factor = int_factor::<N, $t>().map_err(D::Error::custom)?;
+                    let scaled = F::deserialize_with(deserializer)?;
+                    if scaled % factor == 0 {
+                        Ok(scaled / factor)
+                    } else {
+                        Err(D::Error::custom(format_args!(
+                            "{scaled} is not a multiple of the scale factor {N}"
+                        )))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+fn int_factor<const N: u64, T>() -> Result<T, &'static str>
+where
+    T: TryFrom<u64>,
+{
+    match N {
+        0 => Err(ZERO_FACTOR),
+        _ => T::try_from(N).map_err(|_| "scale factor out of range"),
+    }
+}
+
+macro_rules! impl_scale_float {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: u64, F> SerializeWith<$t> for Scale<N, F>
+            where
+                F: SerializeWith<$t>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    if N == 0 {
+                        return Err(S::Error::custom(ZERO_FACTOR));
+                    }
+                    F::serialize_with(&(value * N as $t), serializer)
+                }
+            }
+
+            impl<'de, const N: u64, F> DeserializeWith<'de, $t> for Scale<N, F>
+            where
+                F: DeserializeWith<'de, $t>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    if N == 0 {
+                        return Err(D::Error::custom(ZERO_FACTOR));
+                    }
+                    Ok(F::deserialize_with(deserializer)? / N as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Cents(#[serde(with = "sa::Scale::<100, sa::Str>")] i32);
+
+    #[test]
+    fn integer_is_scaled() {
+        check_serialization(Cents(12), json!("1200"));
+        check_serialization(Cents(-3), json!("-300"));
+        check_serialization(Cents(0), json!("0"));
+    }
+
+    #[test]
+    fn integer_not_multiple_of_factor_fails() {
+        serde_json::from_value::<Cents>(json!("1250")).unwrap_err();
+    }
+
+    #[test]
+    fn integer_overflow_fails() {
+        serde_json::to_value(Cents(i32::MAX)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Small(#[serde(with = "sa::Scale::<1000>")] u8);
+
+    #[test]
+    fn factor_out_of_range_fails() {
+        serde_json::to_value(Small(0)).unwrap_err();
+        serde_json::from_value::<Small>(json!(0)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Zero(#[serde(with = "sa::Scale::<0>")] f64);
+
+    #[test]
+    fn zero_factor_fails() {
+        serde_json::to_value(Zero(1.0)).unwrap_err();
+        serde_json::from_value::<Zero>(json!(1.0)).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct BasisPoints(#[serde(with = "sa::Scale::<10000>")] f64);
+
+    #[test]
+    fn float_is_scaled() {
+        check_serialization(BasisPoints(0.25), json!(2500.0));
+        check_serialization(BasisPoints(-1.5), json!(-15000.0));
+    }
+}
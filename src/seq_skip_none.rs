@@ -0,0 +1,99 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Id, OptionFlat, Seq, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize a sequence of options as a sequence of the `Some` values
+///
+/// `None` items are skipped and `Some` items are serialized with `F`. When deserializing, every
+/// item is deserialized with `F` and wrapped in `Some`. This is lossy: `None` items and their
+/// positions are lost, so `[Some(1), None, Some(2)]` roundtrips as `[Some(1), Some(2)]`.
+///
+/// The serialized form is the same as the one of a sequence of the `Some` values serialized with
+/// [`Seq<F>`](Seq), so it can be deserialized directly into e.g. a `Vec<T>` with
+/// [`Seq<F>`](Seq).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Serialize)]
+/// struct Sparse(#[serde(with = "sa::SeqSkipNone::<sa::Str>")] Vec<Option<i32>>);
+///
+/// #[derive(Debug, Deserialize, PartialEq)]
+/// struct Dense(#[serde(with = "sa::Seq::<sa::Str>")] Vec<i32>);
+///
+/// let serialized = serde_json::to_value(Sparse(vec![Some(1), None, Some(2)])).unwrap();
+/// assert_eq!(serialized, json!(["1", "2"]));
+/// let deserialized = serde_json::from_value::<Dense>(serialized).unwrap();
+/// assert_eq!(deserialized, Dense(vec![1, 2]));
+/// # }
+/// ```
+pub struct SeqSkipNone<F = Id>(PhantomData<F>);
+
+impl<F> SeqSkipNone<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, C, T> SerializeWith<C> for SeqSkipNone<F>
+where
+    F: SerializeWith<T>,
+    C: ?Sized,
+    for<'a> &'a C: IntoIterator<Item = &'a Option<T>>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            container
+                .into_iter()
+                .filter_map(Option::as_ref)
+                .map(WithEncoding::<&F, _>::from),
+        )
+    }
+}
+
+impl<'de, F, C, T> DeserializeWith<'de, C> for SeqSkipNone<F>
+where
+    F: DeserializeWith<'de, T>,
+    C: IntoIterator<Item = Option<T>> + FromIterator<Option<T>>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Seq::<OptionFlat<F>>::deserialize_with(deserializer)
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::SeqSkipNone::<crate::Str>")] Vec<Option<u32>>);
+
+    #[test]
+    fn some_items_roundtrip() {
+        check_serialization(Foo(vec![Some(1), Some(2)]), json!(["1", "2"]));
+        check_serialization(Foo(vec![]), json!([]));
+    }
+
+    #[test]
+    fn none_items_are_skipped() {
+        let serialized = serde_json::to_value(Foo(vec![None, Some(1), None, Some(2)])).unwrap();
+        assert_eq!(serialized, json!(["1", "2"]));
+        let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+        assert_eq!(deserialized, Foo(vec![Some(1), Some(2)]));
+    }
+
+    #[test]
+    fn null_item_fails() {
+        serde_json::from_value::<Foo>(json!(["1", null])).unwrap_err();
+    }
+}
@@ -1,6 +1,6 @@
 // Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
 
-use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use crate::{size_hint, DeserializeWith, SerializeWith, WithEncoding};
 use core::{fmt, marker::PhantomData};
 use serde::{
     de::{SeqAccess, Visitor},
@@ -11,6 +11,30 @@ use serde::{
 ///
 /// This adapter causes a sequence to be serialized such that its items are serialized with `F`.
 ///
+/// Items are serialized in iteration order and collected in input order, so collections
+/// preserving insertion order, such as `IndexSet` from the `indexmap` crate, round-trip with their
+/// order intact. Support for `indexmap` types is enabled by the `indexmap` feature.
+///
+/// Any collection that can be iterated by reference and collected from an iterator is supported,
+/// including `Box<[T]>`. Shared slices such as `Rc<[T]>` and `Arc<[T]>` implement `FromIterator`
+/// and can be iterated through `Deref`, but neither they nor references to them implement
+/// [`IntoIterator`], which the bounds below require. Dedicated implementations for them would
+/// conflict with the generic ones under coherence rules, as the standard library may implement
+/// `IntoIterator` for them in the future, until specialization
+/// ([rust-lang/rust#31844](https://github.com/rust-lang/rust/issues/31844)) allows it. They can
+/// instead be serialized with [`Ptr<Seq<F>>`](crate::Ptr) and deserialized through a `Vec<T>`
+/// with [`From<Vec<T>, Seq<F>>`](crate::From), combined with [`Codec`](crate::Codec).
+///
+/// More precisely, serializing a collection `C` requires `&C` to implement [`IntoIterator`] over
+/// references to items, and deserializing requires `C` to implement [`IntoIterator`] and
+/// [`FromIterator`] over items. A newtype wrapping a collection does not implement these traits
+/// unless they are delegated. Alternatively, [`Transparent<Seq<F>>`](crate::Transparent) applies
+/// this adapter to the wrapped collection.
+///
+/// The sequence is always serialized as a serde variable-length sequence, even for arrays whose
+/// length is statically known. Formats like bincode then encode the length. [`Array`](crate::Array)
+/// serializes arrays as serde tuples instead, which omits the length from such formats.
+///
 /// # Example
 /// ```
 /// # #[cfg(feature = "std")] {
@@ -24,6 +48,39 @@ use serde::{
 /// assert_eq!(v, json!(["1", "2"]));
 /// # }
 /// ```
+///
+/// Newtype wrapping a collection:
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Scores(Vec<u32>);
+///
+/// impl sa::Newtype for Scores {
+///     type Inner = Vec<u32>;
+///
+///     fn inner(&self) -> &Vec<u32> {
+///         &self.0
+///     }
+///
+///     fn from_inner(inner: Vec<u32>) -> Self {
+///         Scores(inner)
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Transparent::<sa::Seq<sa::Str>>")] Scores);
+///
+/// let foo = Foo(Scores(vec![1, 2]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
 pub struct Seq<F>(PhantomData<F>);
 
 impl<F> Seq<F> {
@@ -100,20 +157,24 @@ where
     where
         A: SeqAccess<'de>,
     {
-        core::iter::from_fn(|| {
+        let hint = seq.size_hint();
+        let items = core::iter::from_fn(|| {
             seq.next_element::<WithEncoding<F, C::Item>>()
                 .map(|x| x.map(WithEncoding::into_inner))
                 .transpose()
-        })
-        .collect()
+        });
+        size_hint::collect(hint, items)
     }
 }
 
 #[cfg(all(feature = "alloc", test))]
 mod tests {
-    use crate::test_utils::check_serialization;
-    use alloc::{vec, vec::Vec};
-    use serde::{Deserialize, Serialize};
+    use crate::{test_utils::check_serialization, DeserializeWith};
+    use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
+    use serde::{
+        de::{value::Error, DeserializeSeed, IntoDeserializer, SeqAccess},
+        Deserialize, Serialize,
+    };
     use serde_json::json;
 
     #[derive(Debug, Deserialize, PartialEq, Serialize)]
@@ -123,4 +184,82 @@ mod tests {
     fn seq_adapter_roundtrips() {
         check_serialization(Foo(vec![1, 2]), json!(["1", "2"]));
     }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Ints(#[serde(with = "crate::Seq::<crate::Id>")] Vec<u32>);
+
+    #[test]
+    fn capacity_is_reserved_from_size_hint() {
+        let serialized = serde_json::to_value(Ints((0..5000).collect())).unwrap();
+        let deserialized = serde_json::from_value::<Ints>(serialized).unwrap();
+        assert_eq!(deserialized.0, (0..5000).collect::<Vec<_>>());
+        assert_eq!(deserialized.0.capacity(), 5000);
+    }
+
+    struct LyingSeq(vec::IntoIter<u32>);
+
+    impl<'de> SeqAccess<'de> for LyingSeq {
+        type Error = Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            self.0
+                .next()
+                .map(|x| seed.deserialize(x.into_deserializer()))
+                .transpose()
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(usize::MAX)
+        }
+    }
+
+    #[test]
+    fn overstated_size_hint_is_capped() {
+        let seq = serde::de::value::SeqAccessDeserializer::new(LyingSeq(vec![1, 2].into_iter()));
+        let deserialized: Vec<u32> = crate::Seq::<crate::Id>::deserialize_with(seq).unwrap();
+        assert_eq!(deserialized, [1, 2]);
+        assert!(deserialized.capacity() <= 1024 * 1024 / 4);
+    }
+
+    #[test]
+    fn invalid_item_fails() {
+        serde_json::from_value::<Ints>(json!([1, "2", 3])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Boxed(#[serde(with = "crate::Seq::<crate::Str>")] Box<[i32]>);
+
+    #[test]
+    fn boxed_slice_roundtrips() {
+        check_serialization(Boxed(Box::new([1, 2])), json!(["1", "2"]));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Shared(
+        #[serde(
+            with = "crate::Codec::<crate::Ptr<crate::Seq<crate::Str>>, crate::From<Vec<i32>, crate::Seq<crate::Str>>>"
+        )]
+        Rc<[i32]>,
+    );
+
+    #[test]
+    fn shared_slice_roundtrips_through_vec() {
+        check_serialization(Shared(Rc::from([1, 2])), json!(["1", "2"]));
+    }
+
+    #[cfg(all(feature = "indexmap", feature = "std"))]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct WrapIndexSet(#[serde(with = "crate::Seq::<crate::Str>")] indexmap::IndexSet<i32>);
+
+    #[cfg(all(feature = "indexmap", feature = "std"))]
+    #[test]
+    fn seq_adapter_preserves_index_set_order() {
+        let original = WrapIndexSet(indexmap::IndexSet::from_iter([3, 1, 2]));
+        check_serialization(original, json!(["3", "1", "2"]));
+        let deserialized = serde_json::from_value::<WrapIndexSet>(json!(["2", "3", "1"])).unwrap();
+        assert!(deserialized.0.iter().eq(&[2, 3, 1]));
+    }
 }
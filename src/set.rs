@@ -0,0 +1,114 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Id, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize a set as a sequence of sorted items
+///
+/// Items are serialized with `F` and sorted so that the output does not depend on the iteration
+/// order of the set, e.g. for a `HashSet`. This makes the output
+/// reproducible, which matters when it is hashed or signed. Items must implement [`Ord`], and the
+/// order is the one of the items before they are serialized with `F`. Sets already iterating in
+/// this order, like [`BTreeSet`](alloc::collections::BTreeSet), are serialized the same way as with
+/// [`Seq`](crate::Seq).
+///
+/// Deserialization collects the items into the set, whatever their order.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::HashSet;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Set::<sa::Str>")] HashSet<u32>);
+///
+/// let foo = Foo(HashSet::from_iter([3, 1, 20, 2]));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2", "3", "20"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct Set<F = Id>(PhantomData<F>);
+
+impl<F> Set<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, C, T> SerializeWith<C> for Set<F>
+where
+    F: SerializeWith<T>,
+    C: ?Sized,
+    T: Ord,
+    for<'a> &'a C: IntoIterator<Item = &'a T>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut items = container.into_iter().collect::<Vec<_>>();
+        items.sort_unstable();
+        serializer.collect_seq(items.into_iter().map(WithEncoding::<&F, _>::from))
+    }
+}
+
+impl<'de, F, C> DeserializeWith<'de, C> for Set<F>
+where
+    F: DeserializeWith<'de, C::Item>,
+    C: IntoIterator + FromIterator<C::Item>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Seq::<F>::deserialize_with(deserializer)
+    }
+}
+
+#[cfg(all(feature = "std", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::{BTreeSet, HashSet};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::Set::<crate::Str>")] HashSet<i32>);
+
+    #[test]
+    fn items_are_sorted() {
+        check_serialization(
+            Foo(HashSet::from_iter([3, -1, 20, 2])),
+            json!(["-1", "2", "3", "20"]),
+        );
+    }
+
+    #[test]
+    fn output_does_not_depend_on_iteration_order() {
+        let items = (0..100).collect::<Vec<_>>();
+        let expected = bincode::serialize(&Foo(items.iter().copied().collect())).unwrap();
+        for _ in 0..10 {
+            let set = items.iter().rev().copied().collect::<HashSet<_>>();
+            assert_eq!(bincode::serialize(&Foo(set)).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn unsorted_items_are_accepted() {
+        let deserialized = serde_json::from_value::<Foo>(json!(["2", "-1"])).unwrap();
+        assert_eq!(deserialized, Foo(HashSet::from_iter([-1, 2])));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Ordered(#[serde(with = "crate::Set::<crate::Id>")] BTreeSet<char>);
+
+    #[test]
+    fn btree_set_roundtrips() {
+        check_serialization(
+            Ordered(BTreeSet::from(['c', 'a', 'b'])),
+            json!(["a", "b", "c"]),
+        );
+    }
+}
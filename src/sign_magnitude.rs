@@ -0,0 +1,207 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{fields, DeserializeWith, SerializeWith, WithEncoding};
+use core::{fmt, marker::PhantomData};
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Adapter to serialize a signed integer as a sign and a magnitude
+///
+/// The integer is serialized as a struct with fields `sign` and `magnitude`. `sign` is the string
+/// `"+"` or `"-"`, and `magnitude` is the absolute value of the integer as the unsigned integer
+/// type of the same width (e.g. `u8` for `i8`), serialized with `F`. The magnitude of `MIN` is
+/// thus representable, e.g. `-128i8` is serialized with sign `"-"` and magnitude `128u8`.
+///
+/// Zero is serialized with sign `"+"`, but both signs are accepted for zero when deserializing.
+/// Deserializing any other sign token, or a magnitude out of range for the sign, such as `"-"` and
+/// `129` for `i8`, results in an error.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::SignMagnitude::<sa::Id>")] i32);
+///
+/// let foo = Foo(-5);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "sign": "-", "magnitude": 5 }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct SignMagnitude<F = crate::Id>(PhantomData<F>);
+
+impl<F> SignMagnitude<F> {
+    crate::adapter_methods!(pub);
+}
+
+const NAME: &str = "SignMagnitude";
+const FIELDS: &[&str; 2] = &["sign", "magnitude"];
+
+macro_rules! impl_sign_magnitude {
+    ($($t:ty => $u:ty,)*) => {
+        $(
+            impl<F> SerializeWith<$t> for SignMagnitude<F>
+            where
+                F: SerializeWith<$u>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$t,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    fields::serialize_pair(
+                        serializer,
+                        NAME,
+                        FIELDS,
+                        &Sign { negative: *value < 0 },
+                        &WithEncoding::<F, $u>::from(value.unsigned_abs()),
+                    )
+                }
+            }
+
+            impl<'de, F> DeserializeWith<'de, $t> for SignMagnitude<F>
+            where
+                F: DeserializeWith<'de, $u>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$t, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let (sign, magnitude) = fields::deserialize_pair::<
+                        _,
+                        Sign,
+                        WithEncoding<F, $u>,
+                    >(deserializer, NAME, FIELDS)?;
+                    let magnitude = magnitude.into_inner();
+                    let value = if sign.negative {
+                        <$t>::checked_sub_unsigned(0, magnitude)
+                    } else {
+                        <$t>::checked_add_unsigned(0, magnitude)
+                    };
+                    value.ok_or_else(|| {
+                        serde::de::Error::custom(format_args!(
+                            "magnitude {magnitude} out of range for sign {sign}"
+                        ))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_sign_magnitude! {
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+}
+
+struct Sign {
+    negative: bool,
+}
+
+impl fmt::Display for Sign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.negative { "-" } else { "+" })
+    }
+}
+
+impl Serialize for Sign {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Sign {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(SignVisitor)
+    }
+}
+
+struct SignVisitor;
+
+impl Visitor<'_> for SignVisitor {
+    type Value = Sign;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"+\" or \"-\"")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "+" => Ok(Sign { negative: false }),
+            "-" => Ok(Sign { negative: true }),
+            _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::SignMagnitude::<sa::Str>")] i8);
+
+    #[test]
+    fn sign_and_magnitude_roundtrip() {
+        check_serialization(Foo(5), json!({ "sign": "+", "magnitude": "5" }));
+        check_serialization(Foo(-5), json!({ "sign": "-", "magnitude": "5" }));
+        check_serialization(Foo(0), json!({ "sign": "+", "magnitude": "0" }));
+    }
+
+    #[test]
+    fn extremes_roundtrip() {
+        check_serialization(Foo(i8::MIN), json!({ "sign": "-", "magnitude": "128" }));
+        check_serialization(Foo(i8::MAX), json!({ "sign": "+", "magnitude": "127" }));
+    }
+
+    #[test]
+    fn negative_zero_is_accepted() {
+        let v = serde_json::from_value::<Foo>(json!({ "sign": "-", "magnitude": "0" })).unwrap();
+        assert_eq!(v, Foo(0));
+    }
+
+    #[test]
+    fn out_of_range_magnitude_fails() {
+        serde_json::from_value::<Foo>(json!({ "sign": "-", "magnitude": "129" })).unwrap_err();
+        serde_json::from_value::<Foo>(json!({ "sign": "+", "magnitude": "128" })).unwrap_err();
+        serde_json::from_value::<Foo>(json!({ "sign": "-", "magnitude": "256" })).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_sign_fails() {
+        serde_json::from_value::<Foo>(json!({ "sign": "", "magnitude": "1" })).unwrap_err();
+        serde_json::from_value::<Foo>(json!({ "sign": "+-", "magnitude": "1" })).unwrap_err();
+        serde_json::from_value::<Foo>(json!({ "magnitude": "1" })).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Wide(#[serde(with = "sa::SignMagnitude::<sa::Id>")] i128);
+
+    #[test]
+    fn sign_magnitude_roundtrips_with_bincode() {
+        for n in [i128::MIN, -1, 0, 1, i128::MAX] {
+            let serialized = bincode::serialize(&Wide(n)).unwrap();
+            assert_eq!(bincode::deserialize::<Wide>(&serialized).unwrap(), Wide(n));
+        }
+    }
+}
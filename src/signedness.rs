@@ -0,0 +1,235 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{SeqAccess, Visitor},
+    Deserialize, Deserializer, Serializer,
+};
+
+/// Adapter to serialize unsigned bytes as a sequence of signed bytes
+///
+/// Each `u8` is reinterpreted as an `i8` with the same bit pattern, e.g. `200` is serialized as
+/// `-56`. Deserialization performs the reverse reinterpretation into any collection of `u8`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::AsSigned")] Vec<u8>);
+///
+/// let foo = Foo(vec![1, 200]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([1, -56]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct AsSigned;
+
+impl AsSigned {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<T> SerializeWith<T> for AsSigned
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.as_ref().iter().map(|&b| b as i8))
+    }
+}
+
+impl<'de, C> DeserializeWith<'de, C> for AsSigned
+where
+    C: FromIterator<u8>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReinterpretVisitor::<i8, u8, C>::new(|b| b as u8))
+    }
+}
+
+/// Adapter to serialize signed bytes as a sequence of unsigned bytes
+///
+/// Each `i8` is reinterpreted as a `u8` with the same bit pattern, e.g. `-56` is serialized as
+/// `200`. Deserialization performs the reverse reinterpretation into any collection of `i8`.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::AsUnsigned")] Vec<i8>);
+///
+/// let foo = Foo(vec![1, -56]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([1, 200]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+pub struct AsUnsigned;
+
+impl AsUnsigned {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<T> SerializeWith<T> for AsUnsigned
+where
+    T: AsRef<[i8]> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.as_ref().iter().map(|&b| b as u8))
+    }
+}
+
+impl<'de, C> DeserializeWith<'de, C> for AsUnsigned
+where
+    C: FromIterator<i8>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReinterpretVisitor::<u8, i8, C>::new(|b| b as i8))
+    }
+}
+
+struct ReinterpretVisitor<T, U, C> {
+    convert: fn(T) -> U,
+    _c: PhantomData<fn() -> C>,
+}
+
+impl<T, U, C> ReinterpretVisitor<T, U, C> {
+    fn new(convert: fn(T) -> U) -> Self {
+        Self {
+            convert,
+            _c: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, U, C> Visitor<'de> for ReinterpretVisitor<T, U, C>
+where
+    T: Deserialize<'de>,
+    C: FromIterator<U>,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of bytes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        core::iter::from_fn(|| {
+            seq.next_element::<T>()
+                .map(|b| b.map(self.convert))
+                .transpose()
+        })
+        .collect()
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use alloc::{vec, vec::Vec};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Signed(#[serde(with = "crate::AsSigned")] Vec<u8>);
+
+    #[test]
+    fn as_signed_roundtrips() {
+        check_serialization(
+            Signed(vec![0, 127, 128, 200, 255]),
+            json!([0, 127, -128, -56, -1]),
+        );
+    }
+
+    #[test]
+    fn as_signed_preserves_bits_with_bincode() {
+        let original = Signed(vec![0, 127, 128, 200, 255]);
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(
+            serialized,
+            bincode::serialize(&vec![0u8, 127, 128, 200, 255]).unwrap()
+        );
+        let deserialized = bincode::deserialize::<Signed>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn as_signed_rejects_unsigned_bytes() {
+        serde_json::from_value::<Signed>(json!([200])).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Unsigned(#[serde(with = "crate::AsUnsigned")] Vec<i8>);
+
+    #[test]
+    fn as_unsigned_roundtrips() {
+        check_serialization(
+            Unsigned(vec![0, 127, -128, -56, -1]),
+            json!([0, 127, 128, 200, 255]),
+        );
+    }
+
+    #[test]
+    fn as_unsigned_preserves_bits_with_bincode() {
+        let original = Unsigned(vec![0, 127, -128, -56, -1]);
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(
+            serialized,
+            bincode::serialize(&Vec::<i8>::from([0, 127, -128, -56, -1])).unwrap()
+        );
+        let deserialized = bincode::deserialize::<Unsigned>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+
+/// Maximum number of bytes preallocated from an untrusted size hint
+#[cfg(feature = "alloc")]
+const MAX_PREALLOCATION: usize = 1024 * 1024;
+
+/// Returns the number of items of type `T` to preallocate from `hint`
+///
+/// `hint` usually comes from the input and is only trusted up to a limit so that a malicious hint
+/// cannot cause a large allocation.
+#[cfg(feature = "alloc")]
+pub(crate) fn capacity<T>(hint: Option<usize>) -> usize {
+    hint.unwrap_or(0)
+        .min(MAX_PREALLOCATION / size_of::<T>().max(1))
+}
+
+/// Collects fallible items, preallocating capacity from `hint`
+///
+/// Items are buffered in a `Vec` preallocated according to [`capacity`] before being collected
+/// into `C`. Without the `alloc` feature, nothing is preallocated.
+pub(crate) fn collect<I, T, C, E>(hint: Option<usize>, items: I) -> Result<C, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    C: FromIterator<T>,
+{
+    #[cfg(feature = "alloc")]
+    {
+        let mut buffer = Vec::with_capacity(capacity::<T>(hint));
+        for item in items {
+            buffer.push(item?);
+        }
+        Ok(buffer.into_iter().collect())
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        let _ = hint;
+        items.collect()
+    }
+}
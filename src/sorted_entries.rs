@@ -0,0 +1,96 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, Id, SerializeWith, WithEncoding};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize a map as a sequence of entries sorted by key
+///
+/// `F` is used to serialize keys and `G` is used to serialize values. Each entry is serialized as
+/// a pair, like [`MapAsSeq`](crate::MapAsSeq) does, but entries are sorted by key so that the
+/// output does not depend on the iteration order of the map, e.g. for a
+/// `HashMap`. Keys must implement [`Ord`], and the order is the one of
+/// the keys before they are serialized with `F`.
+///
+/// Deserialization collects the entries into the map, whatever their order.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::collections::HashMap;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo(#[serde(with = "serdapt::SortedEntries::<serdapt::Id>")] HashMap<String, i32>);
+///
+/// let foo = Foo(HashMap::from_iter([("b".into(), 2), ("a".into(), 1), ("c".into(), 3)]));
+/// let v = serde_json::to_value(foo).unwrap();
+/// assert_eq!(v, json!([["a", 1], ["b", 2], ["c", 3]]));
+/// # }
+/// ```
+pub struct SortedEntries<F = Id, G = Id>(PhantomData<(F, G)>);
+
+impl<F, G> SortedEntries<F, G> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, G, C, K, V> SerializeWith<C> for SortedEntries<F, G>
+where
+    F: SerializeWith<K>,
+    G: SerializeWith<V>,
+    C: ?Sized,
+    K: Ord,
+    for<'a> &'a C: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    fn serialize_with<S: Serializer>(container: &C, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entries = container.into_iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|&(k, _)| k);
+        serializer.collect_seq(entries.into_iter().map(WithEncoding::<(&F, &G), _>::from))
+    }
+}
+
+impl<'de, F, G, C, K, V> DeserializeWith<'de, C> for SortedEntries<F, G>
+where
+    F: DeserializeWith<'de, K>,
+    G: DeserializeWith<'de, V>,
+    C: IntoIterator<Item = (K, V)> + FromIterator<(K, V)>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Seq::<(F, G)>::deserialize_with(deserializer)
+    }
+}
+
+#[cfg(all(feature = "std", test))]
+mod tests {
+    use crate::test_utils::check_serialization;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::SortedEntries::<crate::Id, crate::Str>")] HashMap<u32, i32>);
+
+    #[test]
+    fn entries_are_sorted_by_key() {
+        check_serialization(
+            Foo(HashMap::from_iter([(3, -3), (1, -1), (20, -20), (2, -2)])),
+            json!([[1, "-1"], [2, "-2"], [3, "-3"], [20, "-20"]]),
+        );
+    }
+
+    #[test]
+    fn unsorted_entries_are_accepted() {
+        let deserialized = serde_json::from_value::<Foo>(json!([[2, "-2"], [1, "-1"]])).unwrap();
+        assert_eq!(deserialized, Foo(HashMap::from_iter([(1, -1), (2, -2)])));
+    }
+
+    #[test]
+    fn empty_map_roundtrips() {
+        check_serialization(Foo(HashMap::new()), json!([]));
+    }
+}
@@ -10,6 +10,11 @@ use serde::{de::Visitor, Deserializer, Serializer};
 
 /// Adapter to serialize types using their [`Display`] and [`FromStr`] implementations
 ///
+/// Deserializing always produces an owned value parsed from the string, so this adapter does not
+/// support borrowed `&str` targets. An implementation for `&str` would conflict with the one for
+/// [`FromStr`] types. Zero-copy deserialization of `&str` is supported by [`Id`](crate::Id)
+/// instead, with the `#[serde(borrow)]` attribute, when the format can lend strings.
+///
 /// # Example
 /// ```
 /// use serde::{Deserialize, Serialize};
@@ -113,4 +118,15 @@ mod tests {
     fn str_adapter_roundtrips() {
         check_serialization(Foo(33), json!("33"));
     }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Borrowed<'a>(#[serde(borrow, with = "crate::Id")] &'a str);
+
+    #[test]
+    fn borrowed_str_is_deserialized_with_id() {
+        let serialized = bincode::serialize(&Borrowed("foo")).unwrap();
+        let deserialized = bincode::deserialize::<Borrowed<'_>>(&serialized).unwrap();
+        assert_eq!(deserialized, Borrowed("foo"));
+        assert!(serialized.as_ptr_range().contains(&deserialized.0.as_ptr()));
+    }
 }
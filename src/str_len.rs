@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, StrDeserializer},
+        Visitor,
+    },
+    Deserializer, Serializer,
+};
+
+/// Adapter to check that a string has between `MIN` and `MAX` characters before deserializing it
+/// with `F`
+///
+/// Length is counted in [`char`]s, i.e. Unicode scalar values, and bounds are inclusive. Input must
+/// be a string. Serialization is forwarded to `F` unchanged.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Username<'a>(#[serde(borrow, with = "sa::StrLen::<3, 8>")] &'a str);
+///
+/// let deserialized = serde_json::from_str::<Username<'_>>(r#""émile""#).unwrap();
+/// assert_eq!(deserialized, Username("émile"));
+/// assert!(serde_json::from_str::<Username<'_>>(r#""li""#).is_err());
+/// ```
+pub struct StrLen<const MIN: usize, const MAX: usize, F = crate::Id>(PhantomData<F>);
+
+impl<const MIN: usize, const MAX: usize, F> StrLen<MIN, MAX, F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<const MIN: usize, const MAX: usize, F, T> SerializeWith<T> for StrLen<MIN, MAX, F>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, const MIN: usize, const MAX: usize, F, T> DeserializeWith<'de, T> for StrLen<MIN, MAX, F>
+where
+    F: DeserializeWith<'de, T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrLenVisitor::<MIN, MAX, F, T>::new())
+    }
+}
+
+struct StrLenVisitor<const MIN: usize, const MAX: usize, F, T> {
+    _f: PhantomData<F>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<const MIN: usize, const MAX: usize, F, T> StrLenVisitor<MIN, MAX, F, T> {
+    fn new() -> Self {
+        Self {
+            _f: PhantomData,
+            _t: PhantomData,
+        }
+    }
+
+    fn check<E>(v: &str) -> Result<(), E>
+    where
+        E: serde::de::Error,
+    {
+        let len = v.chars().count();
+        if (MIN..=MAX).contains(&len) {
+            Ok(())
+        } else {
+            Err(E::custom(format_args!(
+                "expected a string of {MIN} to {MAX} characters, got {len} characters"
+            )))
+        }
+    }
+}
+
+impl<'de, const MIN: usize, const MAX: usize, F, T> Visitor<'de> for StrLenVisitor<MIN, MAX, F, T>
+where
+    F: DeserializeWith<'de, T>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string of {MIN} to {MAX} characters")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::check(v)?;
+        F::deserialize_with(StrDeserializer::new(v))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::check(v)?;
+        F::deserialize_with(BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Code(#[serde(with = "sa::StrLen::<2, 3, sa::Str>")] u32);
+
+    #[test]
+    fn string_within_bounds_is_accepted() {
+        check_serialization(Code(12), json!("12"));
+        check_serialization(Code(123), json!("123"));
+    }
+
+    #[test]
+    fn string_out_of_bounds_is_rejected() {
+        serde_json::from_value::<Code>(json!("1")).unwrap_err();
+        serde_json::from_value::<Code>(json!("1234")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Name<'a>(#[serde(borrow, with = "sa::StrLen::<1, 2>")] &'a str);
+
+    #[test]
+    fn length_is_counted_in_chars() {
+        let deserialized = serde_json::from_str::<Name<'_>>(r#""éé""#).unwrap();
+        assert_eq!(deserialized, Name("éé"));
+        serde_json::from_str::<Name<'_>>(r#""""#).unwrap_err();
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{fields, DeserializeWith, SerializeWith, WithEncoding};
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+use serde::{
+    de::{Error as _, IgnoredAny, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize a value as a tag and a payload
+///
+/// The value is converted to a `(tag, payload)` pair and back with its [`TagPayload`]
+/// implementation, which is supplied by the user and allows tagging any type, typically an enum.
+/// The tag is serialized with `T` and the payload with `C`. All payloads share a single type, which
+/// can itself be an enum or a dynamic value if variants carry different data.
+///
+/// The representation is selected by `M`:
+/// - [`AdjacentTag`] serializes a struct with a tag field and a content field, whose names are
+///   provided by a [`TagFields`] implementation, e.g. `{ "type": tag, "value": payload }`.
+/// - [`ExternalTag`] serializes a single-entry map from the tag to the payload, e.g.
+///   `{ tag: payload }`.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {
+///     Circle(f64),
+///     Square(f64),
+/// }
+///
+/// impl sa::TagPayload for Shape {
+///     type Tag = String;
+///     type Payload = f64;
+///     type Error = String;
+///
+///     fn to_tag_payload(&self) -> (String, f64) {
+///         match *self {
+///             Shape::Circle(radius) => ("circle".to_owned(), radius),
+///             Shape::Square(side) => ("square".to_owned(), side),
+///         }
+///     }
+///
+///     fn from_tag_payload(tag: String, x: f64) -> Result<Self, String> {
+///         match &*tag {
+///             "circle" => Ok(Shape::Circle(x)),
+///             "square" => Ok(Shape::Square(x)),
+///             _ => Err(format!("unknown shape {tag}")),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Tagged::<sa::Id, sa::Str, sa::AdjacentTag>")] Shape);
+///
+/// let foo = Foo(Shape::Circle(1.5));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "type": "circle", "value": "1.5" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Bar(#[serde(with = "sa::Tagged::<sa::Id, sa::Id, sa::ExternalTag>")] Shape);
+///
+/// let bar = Bar(Shape::Square(2.0));
+/// let serialized = serde_json::to_value(&bar).unwrap();
+/// assert_eq!(serialized, json!({ "square": 2.0 }));
+/// let deserialized = serde_json::from_value::<Bar>(serialized).unwrap();
+/// assert_eq!(deserialized, bar);
+/// ```
+pub struct Tagged<T = crate::Id, C = crate::Id, M = AdjacentTag> {
+    _t: PhantomData<T>,
+    _c: PhantomData<C>,
+    _m: PhantomData<M>,
+}
+
+impl<G, C, M> Tagged<G, C, M> {
+    crate::adapter_methods!(pub);
+}
+
+/// Conversion of a value to and from the tag and payload serialized by [`Tagged`]
+pub trait TagPayload: Sized {
+    /// Tag type
+    type Tag;
+    /// Payload type
+    type Payload;
+    /// Error returned when a tag and payload do not make a valid value
+    type Error: Display;
+
+    /// Returns the tag and payload representing this value
+    fn to_tag_payload(&self) -> (Self::Tag, Self::Payload);
+
+    /// Builds a value from its tag and payload
+    fn from_tag_payload(tag: Self::Tag, payload: Self::Payload) -> Result<Self, Self::Error>;
+}
+
+/// Representation of the tag and payload serialized by [`Tagged`]
+pub trait TagMode {
+    /// Names of the tag and content fields, or `None` to serialize a single-entry map from the
+    /// tag to the payload
+    const FIELDS: Option<&'static [&'static str; 2]>;
+}
+
+/// Mode serializing the tag and the payload as two fields of a struct
+///
+/// The field names are provided by `N`.
+pub struct AdjacentTag<N = TypeValue>(PhantomData<N>);
+
+impl<N> TagMode for AdjacentTag<N>
+where
+    N: TagFields,
+{
+    const FIELDS: Option<&'static [&'static str; 2]> = Some(&[N::TAG, N::CONTENT]);
+}
+
+/// Mode serializing a single-entry map from the tag to the payload
+pub struct ExternalTag;
+
+impl TagMode for ExternalTag {
+    const FIELDS: Option<&'static [&'static str; 2]> = None;
+}
+
+/// Names of the fields used by the [`AdjacentTag`] mode
+pub trait TagFields {
+    /// Name of the field holding the tag
+    const TAG: &'static str;
+    /// Name of the field holding the payload
+    const CONTENT: &'static str;
+}
+
+/// Field names `type` and `value`
+pub struct TypeValue;
+
+impl TagFields for TypeValue {
+    const TAG: &'static str = "type";
+    const CONTENT: &'static str = "value";
+}
+
+const NAME: &str = "Tagged";
+
+impl<T, C, M, U> SerializeWith<U> for Tagged<T, C, M>
+where
+    T: SerializeWith<U::Tag>,
+    C: SerializeWith<U::Payload>,
+    M: TagMode,
+    U: TagPayload,
+{
+    fn serialize_with<S: Serializer>(value: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        let (tag, payload) = value.to_tag_payload();
+        let tag = WithEncoding::<&T, _>::from(&tag);
+        let payload = WithEncoding::<&C, _>::from(&payload);
+        match M::FIELDS {
+            Some(fields) => fields::serialize_pair(serializer, NAME, fields, &tag, &payload),
+            None => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&tag, &payload)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de, T, C, M, U> DeserializeWith<'de, U> for Tagged<T, C, M>
+where
+    T: DeserializeWith<'de, U::Tag>,
+    C: DeserializeWith<'de, U::Payload>,
+    M: TagMode,
+    U: TagPayload,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<U, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (tag, payload) = match M::FIELDS {
+            Some(fields) => fields::deserialize_pair::<
+                _,
+                WithEncoding<T, U::Tag>,
+                WithEncoding<C, U::Payload>,
+            >(deserializer, NAME, fields)?,
+            None => deserializer.deserialize_map(ExternalVisitor::<T, C, U>::new())?,
+        };
+        U::from_tag_payload(tag.into_inner(), payload.into_inner()).map_err(D::Error::custom)
+    }
+}
+
+struct ExternalVisitor<T, C, U> {
+    _t: PhantomData<T>,
+    _c: PhantomData<C>,
+    _u: PhantomData<fn() -> U>,
+}
+
+impl<T, C, U> ExternalVisitor<T, C, U> {
+    fn new() -> Self {
+        ExternalVisitor {
+            _t: PhantomData,
+            _c: PhantomData,
+            _u: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, C, U> Visitor<'de> for ExternalVisitor<T, C, U>
+where
+    T: DeserializeWith<'de, U::Tag>,
+    C: DeserializeWith<'de, U::Payload>,
+    U: TagPayload,
+{
+    type Value = (WithEncoding<T, U::Tag>, WithEncoding<C, U::Payload>);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map with a single entry")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let entry = map
+            .next_entry()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        match map.next_entry::<IgnoredAny, IgnoredAny>()? {
+            Some(_) => Err(A::Error::invalid_length(2, &self)),
+            None => Ok(entry),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq)]
+    enum Level {
+        Low(u8),
+        High(u8),
+    }
+
+    impl sa::TagPayload for Level {
+        type Tag = char;
+        type Payload = u8;
+        type Error = &'static str;
+
+        fn to_tag_payload(&self) -> (char, u8) {
+            match *self {
+                Level::Low(n) => ('l', n),
+                Level::High(n) => ('h', n),
+            }
+        }
+
+        fn from_tag_payload(tag: char, n: u8) -> Result<Self, Self::Error> {
+            match tag {
+                'l' => Ok(Level::Low(n)),
+                'h' => Ok(Level::High(n)),
+                _ => Err("no such level"),
+            }
+        }
+    }
+
+    struct KindData;
+
+    impl sa::TagFields for KindData {
+        const TAG: &'static str = "kind";
+        const CONTENT: &'static str = "data";
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Adjacent(
+        #[serde(with = "sa::Tagged::<sa::Id, sa::Str, sa::AdjacentTag<KindData>>")] Level,
+    );
+
+    #[test]
+    fn adjacent_mode_uses_configured_field_names() {
+        check_serialization(Adjacent(Level::Low(1)), json!({ "kind": "l", "data": "1" }));
+        let deserialized =
+            serde_json::from_value::<Adjacent>(json!({ "data": "2", "kind": "h" })).unwrap();
+        assert_eq!(deserialized, Adjacent(Level::High(2)));
+    }
+
+    #[test]
+    fn adjacent_mode_roundtrips_with_bincode() {
+        let original = Adjacent(Level::High(9));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Adjacent>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct External(#[serde(with = "sa::Tagged::<sa::Id, sa::Id, sa::ExternalTag>")] Level);
+
+    #[test]
+    fn external_mode_serializes_single_entry_map() {
+        check_serialization(External(Level::High(3)), json!({ "h": 3 }));
+    }
+
+    #[test]
+    fn external_mode_rejects_other_entry_counts() {
+        serde_json::from_value::<External>(json!({})).unwrap_err();
+        serde_json::from_value::<External>(json!({ "h": 3, "l": 4 })).unwrap_err();
+    }
+
+    #[test]
+    fn unknown_tag_is_rejected() {
+        let e = serde_json::from_value::<External>(json!({ "m": 3 })).unwrap_err();
+        assert_eq!(e.to_string(), "no such level");
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt::Debug, marker::PhantomData};
+use serde::{Deserializer, Serializer};
+
+/// Adapter calling hook `L` with values going through adapter `F`
+///
+/// The hook is called with the value before it is serialized with `F`, and with the value after
+/// it is deserialized with `F`. Serialization and deserialization are otherwise forwarded to `F`
+/// unchanged. This helps debugging nested adapters.
+///
+/// Hooks implement [`TapHook`]. With the `log` feature, `LogHook` logs values at the debug level.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::fmt::Debug;
+///
+/// struct Print;
+///
+/// impl sa::TapHook for Print {
+///     fn serializing<T: Debug + ?Sized>(value: &T) {
+///         println!("Serializing {value:?}");
+///     }
+///
+///     fn deserialized<T: Debug>(value: &T) {
+///         println!("Deserialized {value:?}");
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Seq::<sa::Tap<sa::Str, Print>>")] Vec<u32>);
+///
+/// let foo = Foo(vec![1, 2]);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(["1", "2"]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct Tap<F, L> {
+    _f: PhantomData<F>,
+    _l: PhantomData<L>,
+}
+
+impl<F, L> Tap<F, L> {
+    crate::adapter_methods!(pub);
+}
+
+/// Hook called by [`Tap`]
+pub trait TapHook {
+    /// Called with a value about to be serialized
+    fn serializing<T: Debug + ?Sized>(value: &T);
+
+    /// Called with a value that was just deserialized
+    fn deserialized<T: Debug>(value: &T);
+}
+
+/// Hook logging values at the debug level with the [`log`] crate
+#[cfg(feature = "log")]
+pub struct LogHook;
+
+#[cfg(feature = "log")]
+impl TapHook for LogHook {
+    fn serializing<T: Debug + ?Sized>(value: &T) {
+        log::debug!("Serializing {value:?}");
+    }
+
+    fn deserialized<T: Debug>(value: &T) {
+        log::debug!("Deserialized {value:?}");
+    }
+}
+
+impl<F, L, T> SerializeWith<T> for Tap<F, L>
+where
+    F: SerializeWith<T>,
+    L: TapHook,
+    T: Debug + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        L::serializing(value);
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, F, L, T> DeserializeWith<'de, T> for Tap<F, L>
+where
+    F: DeserializeWith<'de, T>,
+    L: TapHook,
+    T: Debug,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = F::deserialize_with(deserializer)?;
+        L::deserialized(&value);
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use core::{
+        fmt::Debug,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    static SERIALIZED: AtomicUsize = AtomicUsize::new(0);
+    static DESERIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+    struct Count;
+
+    impl sa::TapHook for Count {
+        fn serializing<T: Debug + ?Sized>(_: &T) {
+            SERIALIZED.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn deserialized<T: Debug>(_: &T) {
+            DESERIALIZED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Array::<sa::Tap<sa::Str, Count>>")] [i32; 3]);
+
+    #[test]
+    fn hook_is_called_for_each_value() {
+        check_serialization(Foo([1, -2, 3]), json!(["1", "-2", "3"]));
+        assert_eq!(SERIALIZED.load(Ordering::Relaxed), 3);
+        assert_eq!(DESERIALIZED.load(Ordering::Relaxed), 3);
+    }
+
+    #[cfg(feature = "log")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Logged(#[serde(with = "sa::Tap::<sa::Id, sa::LogHook>")] bool);
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_hook_passes_through() {
+        check_serialization(Logged(true), json!(true));
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display};
+use serde::{ser::Impossible, Serialize, Serializer};
+
+/// Serializer capturing primitive values as text
+///
+/// Strings and characters are captured as is, and other primitives are formatted with their
+/// [`Display`] implementation. Compound values result in an error.
+pub(crate) struct TextSerializer;
+
+#[derive(Debug)]
+pub(crate) struct TextError(String);
+
+impl Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl serde::ser::StdError for TextError {}
+
+impl serde::ser::Error for TextError {
+    fn custom<T: Display>(msg: T) -> Self {
+        TextError(msg.to_string())
+    }
+}
+
+fn unsupported() -> TextError {
+    TextError("expected a value serializable as text".into())
+}
+
+macro_rules! serialize_display {
+    ($($f:ident $t:ty,)*) => {
+        $(
+            fn $f(self, v: $t) -> Result<String, TextError> {
+                Ok(v.to_string())
+            }
+        )*
+    };
+}
+
+macro_rules! serialize_unsupported {
+    ($($f:ident $(: $t:ty)?,)*) => {
+        $(
+            fn $f(self $(, _: $t)?) -> Result<String, TextError> {
+                Err(unsupported())
+            }
+        )*
+    };
+}
+
+impl Serializer for TextSerializer {
+    type Ok = String;
+    type Error = TextError;
+    type SerializeSeq = Impossible<String, TextError>;
+    type SerializeTuple = Impossible<String, TextError>;
+    type SerializeTupleStruct = Impossible<String, TextError>;
+    type SerializeTupleVariant = Impossible<String, TextError>;
+    type SerializeMap = Impossible<String, TextError>;
+    type SerializeStruct = Impossible<String, TextError>;
+    type SerializeStructVariant = Impossible<String, TextError>;
+
+    serialize_display! {
+        serialize_bool bool,
+        serialize_i8 i8,
+        serialize_i16 i16,
+        serialize_i32 i32,
+        serialize_i64 i64,
+        serialize_i128 i128,
+        serialize_u8 u8,
+        serialize_u16 u16,
+        serialize_u32 u32,
+        serialize_u64 u64,
+        serialize_u128 u128,
+        serialize_f32 f32,
+        serialize_f64 f64,
+        serialize_char char,
+        serialize_str &str,
+    }
+
+    serialize_unsupported! {
+        serialize_bytes: &[u8],
+        serialize_none,
+        serialize_unit,
+        serialize_unit_struct: &'static str,
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<String, TextError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<String, TextError> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<String, TextError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<String, TextError>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, TextError> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, TextError> {
+        Err(unsupported())
+    }
+
+    fn collect_str<T>(self, value: &T) -> Result<String, TextError>
+    where
+        T: Display + ?Sized,
+    {
+        Ok(value.to_string())
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter stacking adapter `G` on top of adapter `F`
+///
+/// When serializing, the value bundled with `F` in a [`WithEncoding`] is serialized with `G`.
+/// When deserializing, a [`WithEncoding`] bundling `F` is deserialized with `G`, and the inner
+/// value is returned.
+///
+/// This differs from passing an adapter as a generic parameter of another adapter, e.g.
+/// `Seq<Str>`. In that case, the outer adapter decides how to apply the inner adapter, usually to
+/// a part of the value. With `Then<F, G>`, `G` sees the whole value as already encoded with `F`,
+/// so `G` must support the serializable type [`WithEncoding<F, T>`]. Adapters that are generic
+/// over any serializable type, such as [`DefaultOnError`](crate::DefaultOnError) or
+/// [`HumanOr`](crate::HumanOr), can thus be stacked on top of any adapter, even if they do not
+/// take an inner adapter themselves.
+///
+/// Converting adapters like [`Convert`](crate::Convert) are deliberately not supported as `F`, as
+/// `G` would need to encode the converted value rather than `WithEncoding<F, T>`. These adapters
+/// already take the adapter to apply to the converted value as a parameter, so
+/// `Convert<u32, Str>` converts to `u32` and serializes the result with [`Str`](crate::Str):
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Meters(u32);
+///
+/// impl From<Meters> for u32 {
+///     fn from(m: Meters) -> u32 {
+///         m.0
+///     }
+/// }
+///
+/// impl From<u32> for Meters {
+///     fn from(n: u32) -> Meters {
+///         Meters(n)
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Convert::<u32, sa::Str>")] Meters);
+///
+/// let foo = Foo(Meters(33));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("33"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// # }
+/// ```
+///
+/// Stacking [`Str`](crate::Str) on top of the conversion with `Then` does not compile:
+///
+/// ```compile_fail
+/// use serdapt as sa;
+/// use serde::Serialize;
+///
+/// #[derive(Clone)]
+/// struct Meters(u32);
+///
+/// impl From<Meters> for u32 {
+///     fn from(m: Meters) -> u32 {
+///         m.0
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Foo(#[serde(with = "sa::Then::<sa::Convert<u32>, sa::Str>")] Meters);
+/// ```
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Then::<sa::Str, sa::DefaultOnError>")] u32);
+///
+/// let foo = Foo(33);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("33"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// let deserialized = serde_json::from_value::<Foo>(json!("garbage")).unwrap();
+/// assert_eq!(deserialized, Foo(0));
+/// # }
+/// ```
+pub struct Then<F, G>(PhantomData<(F, G)>);
+
+impl<F, G> Then<F, G> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+impl<F, G, T> SerializeWith<T> for Then<F, G>
+where
+    F: SerializeWith<T>,
+    G: for<'a> SerializeWith<WithEncoding<&'a F, &'a T>>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        G::serialize_with(&WithEncoding::<&F, _>::from(value), serializer)
+    }
+}
+
+impl<'de, F, G, T> DeserializeWith<'de, T> for Then<F, G>
+where
+    F: DeserializeWith<'de, T>,
+    G: DeserializeWith<'de, WithEncoding<F, T>>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        G::deserialize_with(deserializer).map(WithEncoding::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Then::<sa::Str, sa::Id>")] u32);
+
+    #[test]
+    fn then_with_id_is_inner_adapter() {
+        check_serialization(Foo(33), json!("33"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Human(#[serde(with = "sa::Then::<sa::Str, sa::HumanOr<sa::Id, sa::Id>>")] u32);
+
+    #[test]
+    fn outer_adapter_sees_encoded_value() {
+        check_serialization(Human(33), json!("33"));
+        let serialized = bincode::serialize(&Human(33)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Human>(&serialized).unwrap(),
+            Human(33)
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Defaulted(#[serde(with = "sa::Then::<sa::Str, sa::DefaultOnNull>")] u32);
+
+    #[test]
+    fn outer_adapter_applies_to_whole_value() {
+        check_serialization(Defaulted(33), json!("33"));
+        let deserialized = serde_json::from_value::<Defaulted>(json!(null)).unwrap();
+        assert_eq!(deserialized, Defaulted(0));
+        serde_json::from_value::<Defaulted>(json!(33)).unwrap_err();
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize a single-field newtype as its inner value
+///
+/// The inner value is accessed through [`Newtype`] and serialized with `F`. When deserializing, the
+/// inner value is deserialized with `F` and wrapped back into the newtype.
+///
+/// Unlike [`Convert`](crate::Convert), the inner type does not need to be named when using the
+/// adapter, and serialization borrows the inner value instead of cloning and converting the whole
+/// newtype. This makes it convenient for newtypes with phantom type parameters, e.g. typed
+/// identifiers.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+/// use std::marker::PhantomData;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Id<T>(u64, PhantomData<T>);
+///
+/// impl<T> sa::Newtype for Id<T> {
+///     type Inner = u64;
+///
+///     fn inner(&self) -> &u64 {
+///         &self.0
+///     }
+///
+///     fn from_inner(inner: u64) -> Self {
+///         Id(inner, PhantomData)
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Transparent::<sa::Str>")] Id<User>);
+///
+/// let foo = Foo(Id(7, PhantomData));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!("7"));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct Transparent<F = crate::Id>(PhantomData<F>);
+
+impl<F> Transparent<F> {
+    crate::adapter_methods!(pub);
+}
+
+/// Newtype wrapping a single inner value, used by [`Transparent`]
+pub trait Newtype {
+    /// Type of the wrapped value
+    type Inner;
+
+    /// Returns a reference to the wrapped value
+    fn inner(&self) -> &Self::Inner;
+
+    /// Wraps a value
+    fn from_inner(inner: Self::Inner) -> Self;
+}
+
+impl<F, T> SerializeWith<T> for Transparent<F>
+where
+    F: SerializeWith<T::Inner>,
+    T: Newtype,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value.inner(), serializer)
+    }
+}
+
+impl<'de, F, T> DeserializeWith<'de, T> for Transparent<F>
+where
+    F: DeserializeWith<'de, T::Inner>,
+    T: Newtype,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        F::deserialize_with(deserializer).map(T::from_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use core::marker::PhantomData;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq)]
+    struct Key<T>(u32, PhantomData<T>);
+
+    impl<T> sa::Newtype for Key<T> {
+        type Inner = u32;
+
+        fn inner(&self) -> &u32 {
+            &self.0
+        }
+
+        fn from_inner(inner: u32) -> Self {
+            Key(inner, PhantomData)
+        }
+    }
+
+    // Not serializable on purpose, to check that the marker type needs no bounds.
+    #[derive(Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Transparent::<sa::Id>")] Key<Marker>);
+
+    #[test]
+    fn newtype_roundtrips_as_inner_value() {
+        check_serialization(Foo(Key(3, PhantomData)), json!(3));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Keys(#[serde(with = "sa::Array::<sa::Transparent<sa::Str>>")] [Key<Marker>; 2]);
+
+    #[test]
+    fn transparent_composes_with_other_adapters() {
+        check_serialization(
+            Keys([Key(1, PhantomData), Key(2, PhantomData)]),
+            json!(["1", "2"]),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, PartialEq)]
+    struct Registry(std::collections::BTreeMap<u8, bool>);
+
+    #[cfg(feature = "std")]
+    impl sa::Newtype for Registry {
+        type Inner = std::collections::BTreeMap<u8, bool>;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.0
+        }
+
+        fn from_inner(inner: Self::Inner) -> Self {
+            Registry(inner)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Bar(#[serde(with = "sa::Transparent::<sa::Map<sa::Str, sa::Id>>")] Registry);
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn map_newtype_roundtrips() {
+        check_serialization(
+            Bar(Registry([(1, true), (2, false)].into())),
+            json!({ "1": true, "2": false }),
+        );
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, StrDeserializer},
+        Visitor,
+    },
+    Deserializer, Serializer,
+};
+
+/// Adapter to trim characters from both ends of a string before deserializing it with `F`
+///
+/// Characters in the set `M`, which defaults to [`Whitespace`], are removed from both ends of the
+/// input string, and the trimmed string is deserialized with `F`. Input must be a string.
+///
+/// Serialization is forwarded to `F` unchanged.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Trimmed::<sa::Str>")] u32);
+///
+/// let deserialized = serde_json::from_value::<Foo>(json!(" 42 ")).unwrap();
+/// assert_eq!(deserialized, Foo(42));
+/// let serialized = serde_json::to_value(&deserialized).unwrap();
+/// assert_eq!(serialized, json!("42"));
+/// ```
+pub struct Trimmed<F = crate::Id, M = Whitespace>(PhantomData<(F, M)>);
+
+impl<F, M> Trimmed<F, M> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, M, T> SerializeWith<T> for Trimmed<F, M>
+where
+    F: SerializeWith<T>,
+    T: ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value, serializer)
+    }
+}
+
+impl<'de, F, M, T> DeserializeWith<'de, T> for Trimmed<F, M>
+where
+    F: DeserializeWith<'de, T>,
+    M: CharSet,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TrimmedVisitor::<F, M, T>::new())
+    }
+}
+
+/// Set of characters
+pub trait CharSet {
+    /// Returns whether `c` belongs to this set
+    fn contains(c: char) -> bool;
+}
+
+/// Unicode whitespace characters
+///
+/// See [`char::is_whitespace`].
+pub struct Whitespace;
+
+impl CharSet for Whitespace {
+    fn contains(c: char) -> bool {
+        c.is_whitespace()
+    }
+}
+
+/// ASCII whitespace characters
+///
+/// See [`char::is_ascii_whitespace`].
+pub struct AsciiWhitespace;
+
+impl CharSet for AsciiWhitespace {
+    fn contains(c: char) -> bool {
+        c.is_ascii_whitespace()
+    }
+}
+
+struct TrimmedVisitor<F, M, T> {
+    _adapter: PhantomData<(F, M)>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<F, M, T> TrimmedVisitor<F, M, T> {
+    fn new() -> Self {
+        Self {
+            _adapter: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<'de, F, M, T> Visitor<'de> for TrimmedVisitor<F, M, T>
+where
+    F: DeserializeWith<'de, T>,
+    M: CharSet,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        F::deserialize_with(StrDeserializer::new(v.trim_matches(M::contains)))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        F::deserialize_with(BorrowedStrDeserializer::new(v.trim_matches(M::contains)))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Number(#[serde(with = "sa::Trimmed::<sa::Str>")] u32);
+
+    #[test]
+    fn trimmed_number_is_parsed() {
+        check_serialization(Number(42), json!("42"));
+        let deserialized = serde_json::from_value::<Number>(json!("\t 42\n")).unwrap();
+        assert_eq!(deserialized, Number(42));
+        serde_json::from_value::<Number>(json!("4 2")).unwrap_err();
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Borrowed<'a>(#[serde(borrow, with = "sa::Trimmed::<sa::Id>")] &'a str);
+
+    #[test]
+    fn trimmed_str_is_borrowed() {
+        let deserialized = serde_json::from_str::<Borrowed<'_>>(r#"" foo bar ""#).unwrap();
+        assert_eq!(deserialized, Borrowed("foo bar"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Ascii<'a>(#[serde(borrow, with = "sa::Trimmed::<sa::Id, sa::AsciiWhitespace>")] &'a str);
+
+    #[test]
+    fn ascii_whitespace_keeps_other_whitespace() {
+        let deserialized = serde_json::from_str::<Ascii<'_>>("\"\u{a0} foo \"").unwrap();
+        assert_eq!(deserialized, Ascii("\u{a0} foo"));
+    }
+}
@@ -0,0 +1,331 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, StructName, WithEncoding};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize a tuple as a struct with named fields
+///
+/// The struct and field names are provided by the [`StructFields`] implementation of `N`, and the
+/// elements of the tuple are serialized with the tuple of adapters `F`, e.g. `(Str, Id)` for a
+/// pair. The number of field names must match the size of the tuple, otherwise serialization and
+/// deserialization fail.
+///
+/// When deserializing, fields are matched by name in any order. Missing, duplicate and unknown
+/// fields result in an error. Formats serializing structs as sequences, like bincode, are also
+/// supported.
+///
+/// Tuples of up to 16 elements are supported.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct Point;
+///
+/// impl sa::StructName for Point {
+///     const NAME: &'static str = "Point";
+/// }
+///
+/// impl sa::StructFields for Point {
+///     const FIELDS: &'static [&'static str] = &["x", "y"];
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::TupleAsStruct::<Point, (sa::Id, sa::Str)>")] (i32, i32));
+///
+/// let foo = Foo((1, 2));
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "x": 1, "y": "2" }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct TupleAsStruct<N, F>(PhantomData<(N, F)>);
+
+impl<N, F> TupleAsStruct<N, F> {
+    crate::adapter_methods!(pub);
+}
+
+/// Names of the fields of a struct
+pub trait StructFields: StructName {
+    /// Names of the fields, in the order of the tuple elements
+    const FIELDS: &'static [&'static str];
+}
+
+fn check_fields<N: StructFields>(len: usize) -> Result<(), FieldCountMismatch> {
+    if N::FIELDS.len() == len {
+        Ok(())
+    } else {
+        Err(FieldCountMismatch {
+            name: N::NAME,
+            fields: N::FIELDS.len(),
+            len,
+        })
+    }
+}
+
+struct FieldCountMismatch {
+    name: &'static str,
+    fields: usize,
+    len: usize,
+}
+
+impl fmt::Display for FieldCountMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "struct {} has {} field names for a tuple of size {}",
+            self.name, self.fields, self.len
+        )
+    }
+}
+
+macro_rules! impl_tuple_as_struct {
+    ($($idx:tt $types:ident $adapters:ident $xs:ident,)*) => {
+        impl<N, $($types, $adapters),*> SerializeWith<($($types,)*)>
+            for TupleAsStruct<N, ($($adapters,)*)>
+        where
+            N: StructFields,
+            $($adapters: SerializeWith<$types>,)*
+        {
+            fn serialize_with<S: Serializer>(
+                value: &($($types,)*),
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                const LEN: usize = [$($idx),*].len();
+                check_fields::<N>(LEN).map_err(serde::ser::Error::custom)?;
+                let mut out = serializer.serialize_struct(N::NAME, LEN)?;
+                $(
+                    out.serialize_field(
+                        N::FIELDS[$idx],
+                        &WithEncoding::<&$adapters, _>::from(&value.$idx),
+                    )?;
+                )*
+                out.end()
+            }
+        }
+
+        impl<'de, N, $($types, $adapters),*> DeserializeWith<'de, ($($types,)*)>
+            for TupleAsStruct<N, ($($adapters,)*)>
+        where
+            N: StructFields,
+            $($adapters: DeserializeWith<'de, $types>,)*
+        {
+            fn deserialize_with<D>(deserializer: D) -> Result<($($types,)*), D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                check_fields::<N>([$($idx),*].len()).map_err(serde::de::Error::custom)?;
+                deserializer.deserialize_struct(
+                    N::NAME,
+                    N::FIELDS,
+                    TupleAsStructVisitor::<N, ($($adapters,)*), ($($types,)*)> {
+                        _adapters: PhantomData,
+                        _tuple: PhantomData,
+                    },
+                )
+            }
+        }
+
+        impl<'de, N, $($types, $adapters),*> Visitor<'de>
+            for TupleAsStructVisitor<N, ($($adapters,)*), ($($types,)*)>
+        where
+            N: StructFields,
+            $($adapters: DeserializeWith<'de, $types>,)*
+        {
+            type Value = ($($types,)*);
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "struct {}", N::NAME)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                $(
+                    let $xs = seq
+                        .next_element::<WithEncoding<$adapters, $types>>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length($idx, &self))?
+                        .into_inner();
+                )*
+                Ok(($($xs,)*))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                $(let mut $xs = None::<$types>;)*
+                while let Some(field) = map.next_key_seed(FieldIndex(N::FIELDS))? {
+                    match field {
+                        $(
+                            $idx => {
+                                if $xs.is_some() {
+                                    return Err(serde::de::Error::duplicate_field(N::FIELDS[$idx]));
+                                }
+                                $xs = Some(
+                                    map.next_value::<WithEncoding<$adapters, $types>>()?
+                                        .into_inner(),
+                                );
+                            }
+                        )*
+                        _ => unreachable!("field index out of range"),
+                    }
+                }
+                $(
+                    let $xs = $xs
+                        .ok_or_else(|| serde::de::Error::missing_field(N::FIELDS[$idx]))?;
+                )*
+                Ok(($($xs,)*))
+            }
+        }
+    };
+}
+
+struct TupleAsStructVisitor<N, F, T> {
+    _adapters: PhantomData<(N, F)>,
+    _tuple: PhantomData<fn() -> T>,
+}
+
+macro_rules! impl_tuples_as_struct {
+    ([$($done:tt)*]) => {};
+    ([$($done:tt)*] $idx:tt $ty:ident $adapter:ident $x:ident, $($rest:tt)*) => {
+        impl_tuple_as_struct!($($done)* $idx $ty $adapter $x,);
+        impl_tuples_as_struct!([$($done)* $idx $ty $adapter $x,] $($rest)*);
+    };
+}
+
+impl_tuples_as_struct!(
+    []
+    0 T0 A0 x0,
+    1 T1 A1 x1,
+    2 T2 A2 x2,
+    3 T3 A3 x3,
+    4 T4 A4 x4,
+    5 T5 A5 x5,
+    6 T6 A6 x6,
+    7 T7 A7 x7,
+    8 T8 A8 x8,
+    9 T9 A9 x9,
+    10 T10 A10 x10,
+    11 T11 A11 x11,
+    12 T12 A12 x12,
+    13 T13 A13 x13,
+    14 T14 A14 x14,
+    15 T15 A15 x15,
+);
+
+/// Seed to deserialize a field name into its index, rejecting unknown fields
+struct FieldIndex(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for FieldIndex {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field identifier")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        usize::try_from(v)
+            .ok()
+            .filter(|&i| i < self.0.len())
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.0
+            .iter()
+            .position(|&name| name == v)
+            .ok_or_else(|| E::unknown_field(v, self.0))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(core::str::from_utf8(v).map_err(E::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    struct Rgb;
+
+    impl sa::StructName for Rgb {
+        const NAME: &'static str = "Rgb";
+    }
+
+    impl sa::StructFields for Rgb {
+        const FIELDS: &'static [&'static str] = &["r", "g", "b"];
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Color(
+        #[serde(with = "sa::TupleAsStruct::<Rgb, (sa::Id, sa::Str, sa::Id)>")] (u8, u8, u8),
+    );
+
+    #[test]
+    fn tuple_roundtrips_as_struct() {
+        check_serialization(Color((1, 2, 3)), json!({ "r": 1, "g": "2", "b": 3 }));
+    }
+
+    #[test]
+    fn fields_are_accepted_in_any_order() {
+        let v = serde_json::from_value::<Color>(json!({ "b": 3, "r": 1, "g": "2" })).unwrap();
+        assert_eq!(v, Color((1, 2, 3)));
+    }
+
+    #[test]
+    fn missing_duplicate_and_unknown_fields_fail() {
+        serde_json::from_value::<Color>(json!({ "r": 1, "g": "2" })).unwrap_err();
+        serde_json::from_str::<Color>(r#"{ "r": 1, "g": "2", "b": 3, "r": 4 }"#).unwrap_err();
+        serde_json::from_value::<Color>(json!({ "r": 1, "g": "2", "b": 3, "a": 4 })).unwrap_err();
+    }
+
+    #[test]
+    fn tuple_as_struct_roundtrips_with_bincode() {
+        let original = Color((1, 2, 3));
+        let serialized = bincode::serialize(&original).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Color>(&serialized).unwrap(),
+            original
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Pair(#[serde(with = "sa::TupleAsStruct::<Rgb, (sa::Id, sa::Id)>")] (u8, u8));
+
+    #[test]
+    fn field_count_mismatch_fails() {
+        serde_json::to_value(Pair((1, 2))).unwrap_err();
+        serde_json::from_value::<Pair>(json!({ "r": 1, "g": 2 })).unwrap_err();
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::{fmt, marker::PhantomData};
+use serde::{de::Visitor, Deserializer, Serializer};
+
+/// Adapter to serialize `()` as a named unit struct
+///
+/// The name is provided by the [`StructName`] implementation of `N`. Formats that record the name
+/// of unit structs will emit it, unlike the default serialization of `()` which goes through
+/// `serialize_unit` and carries no name.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// struct SentinelName;
+///
+/// impl sa::StructName for SentinelName {
+///     const NAME: &'static str = "Sentinel";
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo {
+///     #[serde(with = "sa::UnitStruct::<SentinelName>")]
+///     sentinel: (),
+/// }
+///
+/// let foo = Foo { sentinel: () };
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!({ "sentinel": null }));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct UnitStruct<N>(PhantomData<N>);
+
+impl<N> UnitStruct<N> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Name of a struct
+pub trait StructName {
+    /// Name of the struct
+    const NAME: &'static str;
+}
+
+impl<N> SerializeWith<()> for UnitStruct<N>
+where
+    N: StructName,
+{
+    fn serialize_with<S: Serializer>(_: &(), serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct(N::NAME)
+    }
+}
+
+impl<'de, N> DeserializeWith<'de, ()> for UnitStruct<N>
+where
+    N: StructName,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<(), D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_unit_struct(N::NAME, UnitStructVisitor::<N>(PhantomData))
+    }
+}
+
+struct UnitStructVisitor<N>(PhantomData<N>);
+
+impl<'de, N> Visitor<'de> for UnitStructVisitor<N>
+where
+    N: StructName,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unit struct {}", N::NAME)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::check_serialization, StructName};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    struct SentinelName;
+
+    impl StructName for SentinelName {
+        const NAME: &'static str = "Sentinel";
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "crate::UnitStruct::<SentinelName>")] ());
+
+    #[test]
+    fn unit_struct_roundtrips() {
+        check_serialization(Foo(()), json!(null));
+    }
+
+    #[test]
+    fn struct_name_is_serialized() {
+        assert_tokens(
+            &Foo(()),
+            &[
+                Token::NewtypeStruct { name: "Foo" },
+                Token::UnitStruct { name: "Sentinel" },
+            ],
+        );
+    }
+
+    #[test]
+    fn mismatched_struct_name_fails() {
+        assert_de_tokens_error::<Foo>(
+            &[
+                Token::NewtypeStruct { name: "Foo" },
+                Token::UnitStruct { name: "Other" },
+            ],
+            "expected Token::UnitStruct { name: \"Other\" } but deserialization wants \
+             Token::UnitStruct { name: \"Sentinel\" }",
+        );
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+use serde::{de::Error as _, Deserializer, Serializer};
+
+/// Adapter to serialize strings as their UTF-8 bytes
+///
+/// Strings are serialized as byte slices with `F`, and deserialized from bytes deserialized with
+/// `F` and validated as UTF-8. Bytes that are not valid UTF-8 result in an error. `F` is typically
+/// [`Bytes`](crate::Bytes), which lets the format represent bytes natively.
+///
+/// Any type implementing `AsRef<str>` can be serialized. [`String`] and `&str` can be
+/// deserialized, the latter only if `F` can deserialize borrowed bytes from the format.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::Utf8::<sa::Bytes>")] String);
+///
+/// let foo = Foo("hé".to_owned());
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!([104, 0xc3, 0xa9]));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// assert!(serde_json::from_value::<Foo>(json!([0xff])).is_err());
+/// # }
+/// ```
+pub struct Utf8<F = crate::Id>(PhantomData<F>);
+
+impl<F> Utf8<F> {
+    crate::adapter_methods!(pub);
+}
+
+impl<F, T> SerializeWith<T> for Utf8<F>
+where
+    F: SerializeWith<[u8]>,
+    T: AsRef<str> + ?Sized,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        F::serialize_with(value.as_ref().as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, F> DeserializeWith<'de, String> for Utf8<F>
+where
+    F: DeserializeWith<'de, Vec<u8>>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::from_utf8(F::deserialize_with(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<'de: 'a, 'a, F> DeserializeWith<'de, &'a str> for Utf8<F>
+where
+    F: DeserializeWith<'de, &'a [u8]>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<&'a str, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        core::str::from_utf8(F::deserialize_with(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use alloc::string::String;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::Utf8::<sa::Bytes>")] String);
+
+    #[test]
+    fn valid_utf8_roundtrips() {
+        check_serialization(Foo("ab".into()), json!([b'a', b'b']));
+        check_serialization(Foo(String::new()), json!([]));
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected() {
+        let e = serde_json::from_value::<Foo>(json!([b'a', 0xff])).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid utf-8 sequence of 1 bytes from index 1"
+        );
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Borrowed<'a>(#[serde(borrow, with = "sa::Utf8::<sa::Bytes>")] &'a str);
+
+    #[test]
+    fn str_can_be_borrowed_from_bytes() {
+        let serialized = bincode::serialize(&Borrowed("héllo")).unwrap();
+        let deserialized = bincode::deserialize::<Borrowed<'_>>(&serialized).unwrap();
+        assert_eq!(deserialized, Borrowed("héllo"));
+        let invalid =
+            bincode::serialize(&sa::WithEncoding::<sa::Bytes, _>::from(&[0xffu8][..])).unwrap();
+        bincode::deserialize::<Borrowed<'_>>(&invalid).unwrap_err();
+    }
+}
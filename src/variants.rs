@@ -0,0 +1,363 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith, WithEncoding};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{DeserializeSeed, EnumAccess, Unexpected, VariantAccess, Visitor},
+    ser::Error as _,
+    Deserializer, Serializer,
+};
+
+/// Adapter to serialize each variant of an enum with its own adapter
+///
+/// `M` implements [`VariantAdapters`] to describe the enum variants and the adapter to use for
+/// the payload of each variant. Each variant is serialized as a serde newtype variant.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use serdapt::{self as sa, VariantAdapters, VariantDeserializer, VariantSerializer};
+/// use serde::{de::VariantAccess, Deserialize, Serialize, Serializer};
+/// use serde_json::json;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Message {
+///     Ping(u32),
+///     Data(Vec<u8>),
+/// }
+///
+/// struct MsgAdapters;
+///
+/// impl VariantAdapters<Message> for MsgAdapters {
+///     const NAME: &'static str = "Message";
+///     const VARIANTS: &'static [&'static str] = &["Ping", "Data"];
+///
+///     fn serialize_variant<S>(
+///         value: &Message,
+///         serializer: VariantSerializer<S>,
+///     ) -> Result<S::Ok, S::Error>
+///     where
+///         S: Serializer,
+///     {
+///         match value {
+///             Message::Ping(n) => serializer.serialize::<sa::Id, _>(0, n),
+///             Message::Data(bytes) => serializer.serialize::<sa::Hex, _>(1, bytes),
+///         }
+///     }
+///
+///     fn deserialize_variant<'de, A>(
+///         variant: VariantDeserializer<'de, A>,
+///     ) -> Result<Message, A::Error>
+///     where
+///         A: VariantAccess<'de>,
+///     {
+///         match variant.index() {
+///             0 => variant.deserialize::<sa::Id, _>().map(Message::Ping),
+///             _ => variant.deserialize::<sa::Hex, _>().map(Message::Data),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Envelope(#[serde(with = "sa::Variants::<MsgAdapters>")] Message);
+///
+/// let envelope = Envelope(Message::Data(vec![0xab, 0xcd]));
+/// let serialized = serde_json::to_value(&envelope).unwrap();
+/// assert_eq!(serialized, json!({ "Data": "abcd" }));
+/// let deserialized = serde_json::from_value::<Envelope>(serialized).unwrap();
+/// assert_eq!(deserialized, envelope);
+/// # }
+/// ```
+pub struct Variants<M>(PhantomData<M>);
+
+impl<M> Variants<M> {
+    /// Serializes value with adapter
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: ?Sized,
+        S: Serializer,
+        Self: SerializeWith<T>,
+    {
+        Self::serialize_with(value, serializer)
+    }
+
+    /// Deserializes value with adapter
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Self: DeserializeWith<'de, T>,
+    {
+        Self::deserialize_with(deserializer)
+    }
+}
+
+/// Description of the variants of enum `T` and of the adapters for their payloads
+pub trait VariantAdapters<T> {
+    /// Name of the enum
+    const NAME: &'static str;
+
+    /// Names of the variants
+    ///
+    /// A variant is identified by its index in this list.
+    const VARIANTS: &'static [&'static str];
+
+    /// Serializes the variant of `value` with [`VariantSerializer::serialize`]
+    fn serialize_variant<S>(value: &T, serializer: VariantSerializer<S>) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Deserializes the variant identified by [`VariantDeserializer::index`] with
+    /// [`VariantDeserializer::deserialize`]
+    fn deserialize_variant<'de, A>(variant: VariantDeserializer<'de, A>) -> Result<T, A::Error>
+    where
+        A: VariantAccess<'de>;
+}
+
+/// Serializer for an enum variant and its payload
+pub struct VariantSerializer<S> {
+    serializer: S,
+    name: &'static str,
+    variants: &'static [&'static str],
+}
+
+impl<S> VariantSerializer<S>
+where
+    S: Serializer,
+{
+    /// Serializes the variant at `index` with `value` as payload serialized with `F`
+    pub fn serialize<F, U>(self, index: u32, value: &U) -> Result<S::Ok, S::Error>
+    where
+        F: SerializeWith<U>,
+        U: ?Sized,
+    {
+        let variant = usize::try_from(index)
+            .ok()
+            .and_then(|i| self.variants.get(i))
+            .ok_or_else(|| S::Error::custom(format_args!("invalid variant index {index}")))?;
+        self.serializer.serialize_newtype_variant(
+            self.name,
+            index,
+            variant,
+            &WithEncoding::<&F, _>::from(value),
+        )
+    }
+}
+
+/// Deserializer for the payload of an enum variant
+pub struct VariantDeserializer<'de, A> {
+    index: u32,
+    access: A,
+    _de: PhantomData<&'de ()>,
+}
+
+impl<'de, A> VariantDeserializer<'de, A>
+where
+    A: VariantAccess<'de>,
+{
+    /// Returns the index of the variant
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Deserializes the payload of the variant with `F`
+    pub fn deserialize<F, U>(self) -> Result<U, A::Error>
+    where
+        F: DeserializeWith<'de, U>,
+    {
+        self.access
+            .newtype_variant::<WithEncoding<F, U>>()
+            .map(WithEncoding::into_inner)
+    }
+}
+
+impl<M, T> SerializeWith<T> for Variants<M>
+where
+    M: VariantAdapters<T>,
+{
+    fn serialize_with<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        M::serialize_variant(
+            value,
+            VariantSerializer {
+                serializer,
+                name: M::NAME,
+                variants: M::VARIANTS,
+            },
+        )
+    }
+}
+
+impl<'de, M, T> DeserializeWith<'de, T> for Variants<M>
+where
+    M: VariantAdapters<T>,
+{
+    fn deserialize_with<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(M::NAME, M::VARIANTS, VariantsVisitor::<M, T>::new())
+    }
+}
+
+struct VariantsVisitor<M, T> {
+    _m: PhantomData<M>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<M, T> VariantsVisitor<M, T> {
+    fn new() -> Self {
+        Self {
+            _m: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<'de, M, T> Visitor<'de> for VariantsVisitor<M, T>
+where
+    M: VariantAdapters<T>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "enum {}", M::NAME)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (index, access) = data.variant_seed(VariantIndex(M::VARIANTS))?;
+        M::deserialize_variant(VariantDeserializer {
+            index,
+            access,
+            _de: PhantomData,
+        })
+    }
+}
+
+/// Seed deserializing a variant identifier into its index
+pub(crate) struct VariantIndex(pub(crate) &'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantIndex {
+    type Value = u32;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantIndex {
+    type Value = u32;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a variant identifier")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        u32::try_from(v)
+            .ok()
+            .filter(|&i| (i as usize) < self.0.len())
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.0
+            .iter()
+            .position(|&name| name == v)
+            .map(|i| i as u32)
+            .ok_or_else(|| E::unknown_variant(v, self.0))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.0
+            .iter()
+            .position(|name| name.as_bytes() == v)
+            .map(|i| i as u32)
+            .ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+}
+
+#[cfg(all(feature = "alloc", test))]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use crate::{VariantAdapters, VariantDeserializer, VariantSerializer};
+    use alloc::{vec, vec::Vec};
+    use serde::{de::VariantAccess, Deserialize, Serialize, Serializer};
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Ping(u32),
+        Data(Vec<u8>),
+    }
+
+    struct MsgAdapters;
+
+    impl VariantAdapters<Message> for MsgAdapters {
+        const NAME: &'static str = "Message";
+        const VARIANTS: &'static [&'static str] = &["Ping", "Data"];
+
+        fn serialize_variant<S>(
+            value: &Message,
+            serializer: VariantSerializer<S>,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match value {
+                Message::Ping(n) => serializer.serialize::<sa::Str, _>(0, n),
+                Message::Data(bytes) => serializer.serialize::<sa::Hex, _>(1, bytes),
+            }
+        }
+
+        fn deserialize_variant<'de, A>(
+            variant: VariantDeserializer<'de, A>,
+        ) -> Result<Message, A::Error>
+        where
+            A: VariantAccess<'de>,
+        {
+            match variant.index() {
+                0 => variant.deserialize::<sa::Str, _>().map(Message::Ping),
+                _ => variant.deserialize::<sa::Hex, _>().map(Message::Data),
+            }
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Envelope(#[serde(with = "sa::Variants::<MsgAdapters>")] Message);
+
+    #[test]
+    fn first_variant_roundtrips() {
+        check_serialization(Envelope(Message::Ping(33)), json!({ "Ping": "33" }));
+    }
+
+    #[test]
+    fn second_variant_roundtrips() {
+        check_serialization(Envelope(Message::Data(vec![0xab])), json!({ "Data": "ab" }));
+    }
+
+    #[test]
+    fn variants_roundtrip_with_bincode() {
+        let original = Envelope(Message::Data(vec![1, 2]));
+        let serialized = bincode::serialize(&original).unwrap();
+        let deserialized = bincode::deserialize::<Envelope>(&serialized).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
+    #[test]
+    fn unknown_variant_fails() {
+        serde_json::from_value::<Envelope>(json!({ "Pong": "33" })).unwrap_err();
+    }
+}
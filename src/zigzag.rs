@@ -0,0 +1,113 @@
+// Copyright (c) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{DeserializeWith, SerializeWith};
+use core::marker::PhantomData;
+use serde::{Deserializer, Serializer};
+
+/// Adapter to serialize signed integers as their zigzag encoding
+///
+/// A signed integer is mapped to the unsigned integer of the same width such that values of small
+/// magnitude map to small values: `0` maps to `0`, `-1` to `1`, `1` to `2`, `-2` to `3`, and so
+/// on. The unsigned integer is serialized with `F`. This keeps small negative integers compact in
+/// formats encoding unsigned integers with a variable length.
+///
+/// # Example
+/// ```
+/// use serdapt as sa;
+/// use serde::{Deserialize, Serialize};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// struct Foo(#[serde(with = "sa::ZigZag::<sa::Id>")] i32);
+///
+/// let foo = Foo(-3);
+/// let serialized = serde_json::to_value(&foo).unwrap();
+/// assert_eq!(serialized, json!(5));
+/// let deserialized = serde_json::from_value::<Foo>(serialized).unwrap();
+/// assert_eq!(deserialized, foo);
+/// ```
+pub struct ZigZag<F = crate::Id>(PhantomData<F>);
+
+impl<F> ZigZag<F> {
+    crate::adapter_methods!(pub);
+}
+
+macro_rules! impl_zigzag {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl<F> SerializeWith<$signed> for ZigZag<F>
+            where
+                F: SerializeWith<$unsigned>,
+            {
+                fn serialize_with<S: Serializer>(
+                    value: &$signed,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let encoded = ((value << 1) ^ (value >> (<$signed>::BITS - 1))) as $unsigned;
+                    F::serialize_with(&encoded, serializer)
+                }
+            }
+
+            impl<'de, F> DeserializeWith<'de, $signed> for ZigZag<F>
+            where
+                F: DeserializeWith<'de, $unsigned>,
+            {
+                fn deserialize_with<D>(deserializer: D) -> Result<$signed, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let encoded = F::deserialize_with(deserializer)?;
+                    Ok(((encoded >> 1) as $signed) ^ -((encoded & 1) as $signed))
+                }
+            }
+        )*
+    };
+}
+
+impl_zigzag!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as sa, test_utils::check_serialization};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Foo(#[serde(with = "sa::ZigZag::<sa::Str>")] i64);
+
+    #[test]
+    fn minus_one_zigzags_to_one() {
+        check_serialization(Foo(-1), json!("1"));
+    }
+
+    #[test]
+    fn zigzag_interleaves_signs() {
+        check_serialization(Foo(0), json!("0"));
+        check_serialization(Foo(1), json!("2"));
+        check_serialization(Foo(-2), json!("3"));
+        check_serialization(Foo(i64::MAX), json!("18446744073709551614"));
+        check_serialization(Foo(i64::MIN), json!("18446744073709551615"));
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Small(#[serde(with = "sa::ZigZag::<sa::Id>")] i8);
+
+    #[test]
+    fn all_bytes_roundtrip() {
+        for n in i8::MIN..=i8::MAX {
+            let serialized = serde_json::to_value(Small(n)).unwrap();
+            assert_eq!(
+                serde_json::from_value::<Small>(serialized).unwrap(),
+                Small(n)
+            );
+        }
+        check_serialization(Small(i8::MIN), json!(255));
+    }
+}